@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
 use crate::UNICODE_DATA;
 
 /// Return a number between 0 (completely different) and 1 (equal) to indicate string similarity
@@ -33,53 +36,52 @@ pub(crate) fn count_different_codepoints_of_shorter_string(first: &str, second:
 
 pub const UNICODEPOINT_UNKNOWN: &str = "<unknown codepoint>";
 
-pub(crate) fn unicode_codepoint_names_lookup(codepoint: &[char]) -> Vec<Option<&'static str>> {
-    let mut result = vec![];
-    for cp in codepoint.iter() {
-        // NOTE: 0000 does not have a newline in front - special handling required!
-        if *cp == '\0' {
-            result.push(Some(&UNICODE_DATA[5..14]));
-            continue;
-        }
+/// Index of the codepoint names of `UNICODE_DATA`
+struct UnicodeNames {
+    by_codepoint: HashMap<u32, &'static str>,
+    by_name: HashMap<&'static str, char>,
+}
 
-        // generic lookup
-        let search_item = format!("\n{:04X};", *cp as u32);
-        for (pos, found) in UNICODE_DATA.match_indices(&search_item) {
-            let name_excerpt: &'static str = &UNICODE_DATA[pos + found.len()..pos + found.len() + 200];
-            result.push(name_excerpt.find(';').map_or(None, |idx| Some(&name_excerpt[..idx])));
+/// Return the index of `UNICODE_DATA`, which is parsed once on first use
+fn unicode_names() -> &'static UnicodeNames {
+    static NAMES: OnceLock<UnicodeNames> = OnceLock::new();
+    NAMES.get_or_init(|| {
+        let mut names = UnicodeNames { by_codepoint: HashMap::new(), by_name: HashMap::new() };
+        for line in UNICODE_DATA.lines() {
+            // every line starts with fields "{hexadecimal codepoint};{name};"
+            let mut fields = line.split(';');
+            let (hex, name) = match (fields.next(), fields.next()) {
+                (Some(h), Some(n)) => (h, n),
+                _ => continue,
+            };
+            if let Ok(cp) = u32::from_str_radix(hex, 16) {
+                names.by_codepoint.insert(cp, name);
+                if let Some(chr) = char::from_u32(cp) {
+                    names.by_name.insert(name, chr);
+                }
+            }
         }
-    }
+        names
+    })
+}
 
-    result
+pub(crate) fn unicode_codepoint_names_lookup(codepoint: &[char]) -> Vec<Option<&'static str>> {
+    let index = unicode_names();
+    codepoint.iter()
+        .filter_map(|cp| index.by_codepoint.get(&(*cp as u32)))
+        .map(|name| Some(*name))
+        .collect()
 }
 
 pub(crate) fn unicode_name_to_codepoint(name: &str) -> Option<char> {
-    let mut result = None;
     let unicode_name = name.trim();
 
-    if name.chars().all(|c| c.is_uppercase() || c.is_numeric() || c.is_whitespace()) {
-        for (pos, _) in UNICODE_DATA.match_indices(&format!(";{};", unicode_name)) {
-            let mut line_start = pos;
-            while !&UNICODE_DATA[line_start..].starts_with('\n') {
-                if line_start == 0 { break }
-                line_start -= 1;
-            }
-
-            // Unicode names also occur in the 11-th field, but unlike field 2
-            // they do not pass this check
-            if pos - line_start > 10 {
-                continue;
-            }
-
-            let slice = &UNICODE_DATA[line_start + 1 .. pos];
-            result = match u32::from_str_radix(slice, 16) {
-                Ok(s) => char::from_u32(s),
-                Err(_) => continue,
-            };
-        }
+    // names like "<control>" are not unique and thus cannot be looked up
+    if !name.chars().all(|c| c.is_uppercase() || c.is_numeric() || c.is_whitespace()) {
+        return None;
     }
 
-    result
+    unicode_names().by_name.get(unicode_name).copied()
 }
 
 #[cfg(test)]
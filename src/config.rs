@@ -1,11 +1,21 @@
 use crate::errors::LibError;
+use crate::input::{Arg, Args};
+use crate::output::{Output, OutputValue};
+use crate::theme::Theme;
 
+use std::collections::BTreeMap;
 use std::default;
 use std::env;
+use std::ffi::OsString;
 use std::fmt;
+use std::fs;
+use std::hash::{Hash, Hasher};
 use std::io;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
-use std::io::Write;
 
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
@@ -14,7 +24,7 @@ use icu::locid::Locale;
 
 /// Global application settings (mainly to configure representation)
 #[non_exhaustive]
-#[derive(Clone, Debug, Hash, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Configuration {
     /// Radix used to represent bytes and integers in the output.
     /// Only radices 2, 10, and 16 are supported. Default: 10.
@@ -44,6 +54,64 @@ pub struct Configuration {
     /// but the default is a human-readable version.
     /// Default: ``Syntax::Human``.
     pub syntax: Syntax,
+    /// The order in which the entries of an association are represented.
+    /// Default: ``AssociationOrder::Insertion``.
+    pub association_order: AssociationOrder,
+    /// If all matching operations are run, only operations with at least this priority are considered.
+    /// Operations with priority 0.0 are never considered. Default: 0.0.
+    pub min_priority: f32,
+    /// If all matching operations are run, only the {top} operations with the highest priority are considered.
+    pub top: Option<usize>,
+    /// If all matching operations are run, operations taking longer than this are aborted.
+    /// Default: 10 seconds.
+    pub op_timeout: Duration,
+    /// Filepath of the locale data file to load. The substring ``{locale}`` is replaced by the locale.
+    /// Default: None, i.e. use the locale data shipped with this program.
+    pub locale_datafile: Option<String>,
+    /// Default arguments per operation name. If an operation is run explicitly with fewer
+    /// arguments than defaults are provided, the remaining arguments are taken from here.
+    pub op_defaults: BTreeMap<String, Vec<String>>,
+    /// Where did the value of a setting originate from? Settings not mentioned kept their default value.
+    pub sources: BTreeMap<String, Source>,
+}
+
+/// Where the value of a setting originates from
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub enum Source {
+    /// the environment variable with the given name
+    Env(&'static str),
+    /// the configuration file at the given path
+    File(PathBuf),
+    /// the CLI option with the given name
+    CommandLine(&'static str),
+    /// the command of the interactive mode with the given name
+    Repl(&'static str),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Env(name) => write!(f, "environment variable {}", name),
+            Source::File(path) => write!(f, "configuration file '{}'", path.display()),
+            Source::CommandLine(name) => write!(f, "CLI option --{}", name),
+            Source::Repl(name) => write!(f, "REPL command :{}", name),
+        }
+    }
+}
+
+/// Content of a configuration file in TOML syntax
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    radix: Option<u8>,
+    hex_upper: Option<bool>,
+    color_scheme: Option<String>,
+    syntax: Option<String>,
+    association_order: Option<String>,
+    locale: Option<String>,
+    locale_datafile: Option<String>,
+    #[serde(default)]
+    op_defaults: BTreeMap<String, Vec<String>>,
 }
 
 impl default::Default for Configuration {
@@ -56,22 +124,52 @@ impl default::Default for Configuration {
             column: None,
             locale: None,
             syntax: Syntax::Human,
+            association_order: AssociationOrder::Insertion,
+            min_priority: 0.0,
+            top: None,
+            op_timeout: Duration::from_secs(10),
+            locale_datafile: None,
+            op_defaults: BTreeMap::new(),
+            sources: BTreeMap::new(),
         }
     }
 }
 
+// NOTE: implemented manually, because f32 does not implement Hash
+impl Hash for Configuration {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.radix.hash(state);
+        self.alpha_upper.hash(state);
+        self.color_scheme.hash(state);
+        self.item.hash(state);
+        self.column.hash(state);
+        self.locale.hash(state);
+        self.syntax.hash(state);
+        self.association_order.hash(state);
+        self.min_priority.to_bits().hash(state);
+        self.top.hash(state);
+        self.op_timeout.hash(state);
+        self.locale_datafile.hash(state);
+        self.op_defaults.hash(state);
+        self.sources.hash(state);
+    }
+}
+
 impl Configuration {
     /// Provided the clap (CLI parsing library) arguments as function arguments, overwrite members of this `Configuration` instance
-    pub fn overwrite_with_clap(&mut self, out_radix: Option<u8>, out_item: Option<isize>, out_column: Option<String>, out_alpha_upper: Option<bool>, out_color_scheme: Option<String>, in_locale: Option<String>, out_syntax: Option<String>) -> Result<(), LibError> {
+    pub fn overwrite_with_clap(&mut self, out_radix: Option<u8>, out_item: Option<isize>, out_column: Option<String>, out_alpha_upper: Option<bool>, out_color_scheme: Option<String>, in_locale: Option<String>, out_syntax: Option<String>, out_association_order: Option<String>) -> Result<(), LibError> {
         if let Some(radix) = out_radix {
-            if !(radix == 2 || radix == 10 || radix == 16) {
-                return Err(LibError::CLIValueError("radix", "Only radices 2, 10, and 16 are supported".to_string()));
-            }
-            self.radix = out_radix.unwrap_or(10) as usize;
+            self.set_radix(radix, Source::CommandLine("radix"))?;
         }
 
         self.item = out_item;
         self.column = out_column.map(|s| s.to_owned());
+        if self.item.is_some() {
+            self.sources.insert("item".to_owned(), Source::CommandLine("item"));
+        }
+        if self.column.is_some() {
+            self.sources.insert("column".to_owned(), Source::CommandLine("column"));
+        }
 
         // ignore "" as selection column name
         if let Some(column_name) = &self.column {
@@ -82,37 +180,103 @@ impl Configuration {
 
         if let Some(alpha_upper) = out_alpha_upper {
             self.alpha_upper = alpha_upper;
+            self.sources.insert("hex_upper".to_owned(), Source::CommandLine("hex-upper"));
         }
 
         if let Some(color_scheme) = out_color_scheme {
-            match ColorScheme::by_name(&color_scheme) {
+            match ColorScheme::resolve(&color_scheme)? {
                 Some(cs) => self.color_scheme = cs,
                 None => return Err(LibError::CLIValueError("color-scheme", "Unknown color scheme".to_string())),
             }
+            self.sources.insert("color_scheme".to_owned(), Source::CommandLine("color-scheme"));
         }
 
         if let Some(locale) = in_locale {
-            self.locale = Self::evaluate_locale(locale)?;
+            self.set_locale(locale, Source::CommandLine("locale"))?;
         }
 
         if let Some(syntax) = out_syntax {
-            self.syntax = match syntax.to_ascii_lowercase().as_str() {
-                "c" => Syntax::C,
-                "c++" | "cpp" => Syntax::Cpp,
-                "golang" | "go" => Syntax::Golang,
-                "human" | "default" => Syntax::Human,
-                "java" => Syntax::Java,
-                "kotlin" => Syntax::Kotlin,
-                "perl" => Syntax::Perl,
-                "python" | "py" => Syntax::Python,
-                "rust" | "rustlang" => Syntax::Rust,
-                _ => return Err(LibError::CLIValueError("syntax", format!("Sorry, syntax '{}' is unsupported", syntax))),
-            };
+            self.set_syntax(&syntax, Source::CommandLine("syntax"))?;
+        }
+
+        if let Some(order) = out_association_order {
+            self.set_association_order(&order, Source::CommandLine("association-order"))?;
         }
 
         Ok(())
     }
 
+    /// Set the radix of integers in the output, which must be 2, 10, or 16
+    pub fn set_radix(&mut self, radix: u8, source: Source) -> Result<(), LibError> {
+        if !(radix == 2 || radix == 10 || radix == 16) {
+            return Err(LibError::CLIValueError("radix", "Only radices 2, 10, and 16 are supported".to_string()));
+        }
+        self.radix = radix as usize;
+        self.sources.insert("radix".to_owned(), source);
+        Ok(())
+    }
+
+    /// Set the locale of the input given by its BCP 47 language tag
+    pub fn set_locale(&mut self, locale: String, source: Source) -> Result<(), LibError> {
+        self.locale = Self::evaluate_locale(locale)?;
+        self.sources.insert("locale".to_owned(), source);
+        Ok(())
+    }
+
+    /// Set the output syntax given by its name
+    pub fn set_syntax(&mut self, syntax: &str, source: Source) -> Result<(), LibError> {
+        self.syntax = match Syntax::by_name(syntax) {
+            Some(s) => s,
+            None => return Err(LibError::CLIValueError("syntax", format!("Sorry, syntax '{}' is unsupported", syntax))),
+        };
+        self.sources.insert("syntax".to_owned(), source);
+        Ok(())
+    }
+
+    /// Set the order of associative arrays in the output given by its name
+    pub fn set_association_order(&mut self, order: &str, source: Source) -> Result<(), LibError> {
+        self.association_order = match AssociationOrder::by_name(order) {
+            Some(o) => o,
+            None => return Err(LibError::CLIValueError("association-order", format!("expected 'insertion', 'key', or 'value', got '{}'", order))),
+        };
+        self.sources.insert("association_order".to_owned(), source);
+        Ok(())
+    }
+
+    /// Provided the clap arguments restricting which operations are run if no operation is specified,
+    /// overwrite members of this `Configuration` instance
+    pub fn overwrite_ranking_filters(&mut self, min_priority: Option<f32>, top: Option<usize>) -> Result<(), LibError> {
+        if let Some(prio) = min_priority {
+            if !(0.0..=1.0).contains(&prio) {
+                return Err(LibError::CLIValueError("min-priority", format!("expected a priority between 0.0 and 1.0, got {}", prio)));
+            }
+            self.min_priority = prio;
+            self.sources.insert("min_priority".to_owned(), Source::CommandLine("min-priority"));
+        }
+
+        if let Some(count) = top {
+            if count == 0 {
+                return Err(LibError::CLIValueError("top", "expected a positive number of operations".to_owned()));
+            }
+            self.top = Some(count);
+            self.sources.insert("top".to_owned(), Source::CommandLine("top"));
+        }
+
+        Ok(())
+    }
+
+    /// Provided the clap argument for the time limit per operation in seconds, overwrite members of this `Configuration` instance
+    pub fn overwrite_timeout(&mut self, seconds: Option<f32>) -> Result<(), LibError> {
+        if let Some(secs) = seconds {
+            match Duration::try_from_secs_f32(secs) {
+                Ok(timeout) if !timeout.is_zero() => self.op_timeout = timeout,
+                _ => return Err(LibError::CLIValueError("timeout", format!("expected a positive number of seconds, got {}", secs))),
+            }
+            self.sources.insert("op_timeout".to_owned(), Source::CommandLine("timeout"));
+        }
+        Ok(())
+    }
+
     /// Reading the defined environment variables for this library, overwrite members of this `Configuration` instance
     pub fn overwrite_with_env(&mut self) -> Result<(), LibError> {
         if let Ok(val) = env::var("OPSTR_RADIX") {
@@ -125,6 +289,7 @@ impl Configuration {
                 },
                 Err(_) => return Err(LibError::CLIValueError("OPSTR_RADIX", "env value is not an integer".to_string())),
             }
+            self.sources.insert("radix".to_owned(), Source::Env("OPSTR_RADIX"));
         }
 
         if let Ok(val) = env::var("OPSTR_HEX_UPPER") {
@@ -132,39 +297,207 @@ impl Configuration {
                 "yes" | "1" | "y" | "true" => true,
                 _ => false,
             };
+            self.sources.insert("hex_upper".to_owned(), Source::Env("OPSTR_HEX_UPPER"));
         }
 
         if let Ok(val) = env::var("OPSTR_COLOR_SCHEME") {
-            match ColorScheme::by_name(&val) {
+            match ColorScheme::resolve(&val)? {
                 Some(cs) => self.color_scheme = cs,
                 None => return Err(LibError::CLIValueError("color-scheme", format!("Unknown color scheme '{}'", val))),
             }
+            self.sources.insert("color_scheme".to_owned(), Source::Env("OPSTR_COLOR_SCHEME"));
+        } else if let Some(theme) = Theme::from_env()? {
+            self.color_scheme = ColorScheme::Custom(Arc::new(theme));
+            self.sources.insert("color_scheme".to_owned(), Source::Env("OPSTR_THEME"));
         }
 
         if let Ok(val) = env::var("OPSTR_LOCALE") {
             // NOTE: there is no trivial locale verification algorithm
             // I expect some failure during the operation later, if the locale is invalid, but must be provided.
             self.locale = Self::evaluate_locale(val)?;
+            self.sources.insert("locale".to_owned(), Source::Env("OPSTR_LOCALE"));
+        }
+
+        if let Ok(val) = env::var("OPSTR_LOCALE_DATAFILE") {
+            self.locale_datafile = Some(val);
+            self.sources.insert("locale_datafile".to_owned(), Source::Env("OPSTR_LOCALE_DATAFILE"));
         }
 
         if let Ok(val) = env::var("OPSTR_SYNTAX") {
-            self.syntax = match val.to_ascii_lowercase().as_str() {
-                "c" => Syntax::C,
-                "c++" | "cpp" => Syntax::Cpp,
-                "golang" | "go" => Syntax::Golang,
-                "human" => Syntax::Human,
-                "java" => Syntax::Java,
-                "kotlin" => Syntax::Kotlin,
-                "perl" => Syntax::Perl,
-                "python" | "py" => Syntax::Python,
-                "rust" | "rustlang" => Syntax::Rust,
-                _ => return Err(LibError::CLIValueError("syntax", format!("Sorry, syntax '{}' is unsupported", val))),
+            self.syntax = match Syntax::by_name(&val) {
+                Some(s) => s,
+                None => return Err(LibError::CLIValueError("syntax", format!("Sorry, syntax '{}' is unsupported", val))),
             };
+            self.sources.insert("syntax".to_owned(), Source::Env("OPSTR_SYNTAX"));
+        }
+
+        if let Ok(val) = env::var("OPSTR_ASSOCIATION_ORDER") {
+            self.association_order = match AssociationOrder::by_name(&val) {
+                Some(o) => o,
+                None => return Err(LibError::CLIValueError("OPSTR_ASSOCIATION_ORDER", format!("expected 'insertion', 'key', or 'value', got '{}'", val))),
+            };
+            self.sources.insert("association_order".to_owned(), Source::Env("OPSTR_ASSOCIATION_ORDER"));
         }
 
         Ok(())
     }
 
+    /// Return the filepaths of existing configuration files in increasing order of precedence:
+    /// the user configuration ``$XDG_CONFIG_HOME/opstr/config.toml`` (defaulting to ``~/.config/opstr/config.toml``)
+    /// and the project configuration ``.opstr.toml`` in the current working directory or its closest ancestor.
+    pub fn default_config_files() -> Vec<PathBuf> {
+        let mut files = vec![];
+
+        if let Some(dir) = Self::config_home() {
+            let user_file = dir.join("opstr").join("config.toml");
+            if user_file.is_file() {
+                files.push(user_file);
+            }
+        }
+
+        if let Ok(cwd) = env::current_dir() {
+            if let Some(project_file) = cwd.ancestors().map(|dir| dir.join(".opstr.toml")).find(|f| f.is_file()) {
+                files.push(project_file);
+            }
+        }
+
+        files
+    }
+
+    /// The base directory of user configuration files, i.e. ``$XDG_CONFIG_HOME`` defaulting to ``~/.config``
+    pub(crate) fn config_home() -> Option<PathBuf> {
+        match env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+            _ => env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")),
+        }
+    }
+
+    /// Reading the default configuration files and the optionally provided `explicit_file`
+    /// (in this order, so later ones take precedence), overwrite members of this `Configuration` instance
+    pub fn overwrite_with_files(&mut self, explicit_file: Option<&Path>) -> Result<(), LibError> {
+        for filepath in Self::default_config_files() {
+            self.overwrite_with_file(&filepath)?;
+        }
+        if let Some(filepath) = explicit_file {
+            self.overwrite_with_file(filepath)?;
+        }
+        Ok(())
+    }
+
+    /// Reading the configuration file at `filepath` in TOML syntax, overwrite members of this `Configuration` instance
+    pub fn overwrite_with_file(&mut self, filepath: &Path) -> Result<(), LibError> {
+        let error = |msg: String| LibError::ConfigFileError(filepath.display().to_string(), msg);
+        let content = fs::read_to_string(filepath).map_err(|e| error(e.to_string()))?;
+        let file: ConfigFile = toml::from_str(&content).map_err(|e| error(e.message().to_owned()))?;
+        let source = Source::File(filepath.to_owned());
+
+        if let Some(radix) = file.radix {
+            if !(radix == 2 || radix == 10 || radix == 16) {
+                return Err(error("Only radices 2, 10, and 16 are supported".to_string()));
+            }
+            self.radix = radix as usize;
+            self.sources.insert("radix".to_owned(), source.clone());
+        }
+
+        if let Some(alpha_upper) = file.hex_upper {
+            self.alpha_upper = alpha_upper;
+            self.sources.insert("hex_upper".to_owned(), source.clone());
+        }
+
+        if let Some(color_scheme) = file.color_scheme {
+            match ColorScheme::resolve(&color_scheme)? {
+                Some(cs) => self.color_scheme = cs,
+                None => return Err(error(format!("Unknown color scheme '{}'", color_scheme))),
+            }
+            self.sources.insert("color_scheme".to_owned(), source.clone());
+        }
+
+        if let Some(syntax) = file.syntax {
+            self.syntax = match Syntax::by_name(&syntax) {
+                Some(s) => s,
+                None => return Err(error(format!("Sorry, syntax '{}' is unsupported", syntax))),
+            };
+            self.sources.insert("syntax".to_owned(), source.clone());
+        }
+
+        if let Some(order) = file.association_order {
+            self.association_order = match AssociationOrder::by_name(&order) {
+                Some(o) => o,
+                None => return Err(error(format!("expected 'insertion', 'key', or 'value' as association_order, got '{}'", order))),
+            };
+            self.sources.insert("association_order".to_owned(), source.clone());
+        }
+
+        if let Some(locale) = file.locale {
+            self.locale = Self::evaluate_locale(locale)?;
+            self.sources.insert("locale".to_owned(), source.clone());
+        }
+
+        if let Some(datafile) = file.locale_datafile {
+            self.locale_datafile = Some(datafile);
+            self.sources.insert("locale_datafile".to_owned(), source.clone());
+        }
+
+        for (op_name, defaults) in file.op_defaults {
+            self.sources.insert(format!("op_defaults.{}", op_name), source.clone());
+            self.op_defaults.insert(op_name, defaults);
+        }
+
+        Ok(())
+    }
+
+    /// Return `args` completed by the default arguments configured for operation `op_name`.
+    /// Default arguments only fill positions not provided by `args`.
+    pub fn args_with_op_defaults(&self, op_name: &str, args: &Args) -> Args {
+        let mut completed = args.clone();
+        if let Some(defaults) = self.op_defaults.get(op_name) {
+            for (i, default) in defaults.iter().enumerate().skip(args.len()) {
+                completed.add(Arg::from_str(default, i));
+            }
+        }
+        completed
+    }
+
+    /// Represent all settings with their values and origin as `Output::Table`
+    pub fn dump(&self) -> Output {
+        #[cfg(feature = "icu")]
+        let locale = self.locale.as_ref().map(|l| l.to_string());
+        #[cfg(not(feature = "icu"))]
+        let locale: Option<String> = None;
+
+        let mut settings = vec![
+            ("radix".to_owned(), self.radix.to_string()),
+            ("hex_upper".to_owned(), self.alpha_upper.to_string()),
+            ("color_scheme".to_owned(), self.color_scheme.to_string()),
+            ("syntax".to_owned(), self.syntax.represent().to_owned()),
+            ("association_order".to_owned(), self.association_order.represent().to_owned()),
+            ("item".to_owned(), self.item.map(|i| i.to_string()).unwrap_or_default()),
+            ("column".to_owned(), self.column.clone().unwrap_or_default()),
+            ("min_priority".to_owned(), self.min_priority.to_string()),
+            ("top".to_owned(), self.top.map(|t| t.to_string()).unwrap_or_default()),
+            ("op_timeout".to_owned(), format!("{:?}", self.op_timeout)),
+            ("locale".to_owned(), locale.unwrap_or_default()),
+            ("locale_datafile".to_owned(), self.locale_datafile.clone().unwrap_or_default()),
+        ];
+        for (op_name, defaults) in self.op_defaults.iter() {
+            settings.push((format!("op_defaults.{}", op_name), format!("{:?}", defaults)));
+        }
+
+        let data = settings.into_iter().map(|(name, value)| {
+            let source = match self.sources.get(&name) {
+                Some(src) => src.to_string(),
+                None => "default".to_owned(),
+            };
+            vec![OutputValue::from_str(&name), OutputValue::from_str(&value), OutputValue::from_str(&source)]
+        }).collect::<Vec<Vec<OutputValue>>>();
+
+        Output::Table {
+            data,
+            column_headers: vec!["setting".to_owned(), "value".to_owned(), "source".to_owned()],
+            notes: vec![],
+        }
+    }
+
     #[cfg(feature = "icu")]
     fn evaluate_locale(locale: String) -> Result<Option<Locale>, LibError> {
         Ok(Some(locale.parse()?))
@@ -181,17 +514,24 @@ impl Configuration {
 /// The default one might be a good choice, but for visually impaired
 /// people, alternatives must be provided. Furthermore personal preferences
 /// shall be addressed.
+///
+/// `Auto` uses the palette of `Default`. Every scheme with colors, including a user-defined `Theme`
+/// (variant `Custom`), only emits them if colors are desired for the stream written to
+/// (see `ColorScheme::auto_colors`). `NoColors` never emits colors.
 #[non_exhaustive]
-#[derive(Copy, Clone, Debug, Default, Hash, PartialEq)]
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
 pub enum ColorScheme {
-    NoColors,
     #[default]
+    Auto,
+    NoColors,
     Default,
     RegularAndBold,
     Red,
     Green,
     Blue,
     White,
+    /// a user-defined theme, shared such that cloning the color scheme is cheap
+    Custom(Arc<Theme>),
 }
 
 impl Eq for ColorScheme {}
@@ -201,6 +541,7 @@ impl ColorScheme {
     pub fn by_name(scheme: &str) -> Option<Self> {
         use ColorScheme::*;
         match scheme.to_ascii_lowercase().as_str() {
+            "auto" => Some(Auto),
             "none" => Some(NoColors),
             "default" => Some(Default),
             "regularandbold" => Some(RegularAndBold),
@@ -212,17 +553,76 @@ impl ColorScheme {
         }
     }
 
-    /// Return the ColorChoice for the configured color scheme
+    /// Take a name and return the corresponding built-in ColorScheme or user-defined `Theme`
+    /// (or None, if unknown). Fails if the theme exists, but is invalid.
+    pub fn resolve(scheme: &str) -> Result<Option<Self>, LibError> {
+        match Self::by_name(scheme) {
+            Some(cs) => Ok(Some(cs)),
+            None => Ok(Theme::by_name(scheme)?.map(|theme| ColorScheme::Custom(Arc::new(theme)))),
+        }
+    }
+
+    /// Return the ColorChoice for the configured color scheme writing to stdout
     pub fn color_choice(&self) -> ColorChoice {
+        self.color_choice_for(io::stdout().is_terminal())
+    }
+
+    /// Return the ColorChoice for the configured color scheme writing to a stream,
+    /// which is a terminal if `is_terminal` is true
+    pub fn color_choice_for(&self, is_terminal: bool) -> ColorChoice {
+        self.color_choice_with(is_terminal, |name| env::var_os(name))
+    }
+
+    /// Like `color_choice_for`, but environment variables are looked up with `var`
+    pub fn color_choice_with(&self, is_terminal: bool, var: impl Fn(&str) -> Option<OsString>) -> ColorChoice {
         match self {
             ColorScheme::NoColors | ColorScheme::RegularAndBold => ColorChoice::Never,
+            _ if !Self::auto_colors_with(is_terminal, var) => ColorChoice::Never,
             _ => ColorChoice::AlwaysAnsi,
         }
     }
 
-    /// Returns the entire list of supported color schemes as a slice
+    /// Decide whether a color scheme emits colors to a stream (a terminal if `is_terminal` is true).
+    /// Follows the conventions of <https://no-color.org> and <https://bixense.com/clicolors/>:
+    /// a non-empty `NO_COLOR` disables colors, a `CLICOLOR_FORCE` other than ``0`` enables them,
+    /// otherwise colors are used for terminals unless `TERM` is ``dumb``.
+    pub fn auto_colors(is_terminal: bool) -> bool {
+        Self::auto_colors_with(is_terminal, |name| env::var_os(name))
+    }
+
+    /// Like `auto_colors`, but environment variables are looked up with `var`
+    pub fn auto_colors_with(is_terminal: bool, var: impl Fn(&str) -> Option<OsString>) -> bool {
+        if var("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return false;
+        }
+        if var("CLICOLOR_FORCE").is_some_and(|v| !v.is_empty() && v != "0") {
+            return true;
+        }
+        if var("TERM").is_some_and(|v| v == "dumb") {
+            return false;
+        }
+        is_terminal
+    }
+
+    /// The canonical name of this color scheme accepted by `ColorScheme::resolve`
+    pub fn name(&self) -> &str {
+        match self {
+            ColorScheme::Auto => "auto",
+            ColorScheme::NoColors => "none",
+            ColorScheme::Default => "default",
+            ColorScheme::RegularAndBold => "regularandbold",
+            ColorScheme::Red => "red",
+            ColorScheme::Green => "green",
+            ColorScheme::Blue => "blue",
+            ColorScheme::White => "white",
+            ColorScheme::Custom(theme) => theme.name(),
+        }
+    }
+
+    /// Returns the entire list of built-in color schemes as a slice
     pub fn all_as_slice() -> &'static [ColorScheme] {
         &[
+            ColorScheme::Auto,
             ColorScheme::NoColors,
             ColorScheme::Default,
             ColorScheme::RegularAndBold,
@@ -233,221 +633,155 @@ impl ColorScheme {
         ]
     }
 
-    /// represents an operation like ``----- hello-world ---------``
-    pub fn op_section(&self, op_name: &str) -> io::Result<()> {
+    /// A stream to stdout with the `ColorChoice` of this color scheme
+    pub fn stdout(&self) -> StandardStream {
+        StandardStream::stdout(self.color_choice())
+    }
+
+    /// A stream to stderr with the `ColorChoice` of this color scheme
+    pub fn stderr(&self) -> StandardStream {
+        StandardStream::stderr(self.color_choice_for(io::stderr().is_terminal()))
+    }
+
+    /// Write `text` to `out` with color specification `cs` (unless no colors shall be used)
+    fn write_colored(&self, out: &mut dyn WriteColor, text: &str, cs: &ColorSpec) -> io::Result<()> {
         if self == &ColorScheme::NoColors {
-            println!("----- {} {}", op_name, "-".repeat((65 - op_name.len()).max(0)));
-            return Ok(());
+            return write!(out, "{}", text);
         }
 
-        let mut stdout = StandardStream::stdout(self.color_choice());
-        match self {
-            ColorScheme::NoColors => {},
-            ColorScheme::Default => {
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Blue)))?;
-                write!(&mut stdout, "----- ")?;
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
-                write!(&mut stdout, "{}", op_name)?;
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Blue)))?;
-                writeln!(&mut stdout, " {}", "-".repeat((65 - op_name.len()).max(0)))?;
-            },
-            ColorScheme::RegularAndBold => {
-                write!(&mut stdout, "----- ")?;
-                stdout.set_color(ColorSpec::new().set_bold(true))?;
-                write!(&mut stdout, "{}", op_name)?;
-                stdout.reset()?;
-                writeln!(&mut stdout, " {}", "-".repeat((65 - op_name.len()).max(0)))?;
-            },
-            ColorScheme::Red => {
-                write!(&mut stdout, "----- ")?;
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Ansi256(1))))?;
-                write!(&mut stdout, "{}", op_name)?;
-                stdout.reset()?;
-                writeln!(&mut stdout, " {}", "-".repeat((65 - op_name.len()).max(0)))?;
-            },
-            ColorScheme::Green => {
-                write!(&mut stdout, "----- ")?;
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Ansi256(34))))?;
-                write!(&mut stdout, "{}", op_name)?;
-                stdout.reset()?;
-                writeln!(&mut stdout, " {}", "-".repeat((65 - op_name.len()).max(0)))?;
-            },
-            ColorScheme::Blue => {
-                write!(&mut stdout, "----- ")?;
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Ansi256(61))))?;
-                write!(&mut stdout, "{}", op_name)?;
-                stdout.reset()?;
-                writeln!(&mut stdout, " {}", "-".repeat((65 - op_name.len()).max(0)))?;
+        out.set_color(cs)?;
+        write!(out, "{}", text)?;
+        out.reset()
+    }
+
+    /// represents an operation like ``----- hello-world ---------``
+    pub fn op_section(&self, out: &mut dyn WriteColor, op_name: &str) -> io::Result<()> {
+        let line = "-".repeat((65 - op_name.len()).max(0));
+        let mut cs = ColorSpec::new();
+        let name_spec: &ColorSpec = match self {
+            ColorScheme::NoColors => {
+                return writeln!(out, "----- {} {}", op_name, line);
             },
-            ColorScheme::White => {
-                write!(&mut stdout, "----- ")?;
-                stdout.set_color(ColorSpec::new().set_fg(Some(Color::Ansi256(236))))?;
-                write!(&mut stdout, "{}", op_name)?;
-                stdout.reset()?;
-                writeln!(&mut stdout, " {}", "-".repeat((65 - op_name.len()).max(0)))?;
+            ColorScheme::Auto | ColorScheme::Default => {
+                let blue = ColorSpec::new().set_fg(Some(Color::Blue)).clone();
+                self.write_colored(out, "----- ", &blue)?;
+                self.write_colored(out, op_name, cs.set_fg(Some(Color::Yellow)))?;
+                self.write_colored(out, &format!(" {}", line), &blue)?;
+                return writeln!(out);
             },
+            ColorScheme::RegularAndBold => cs.set_bold(true),
+            ColorScheme::Red => cs.set_fg(Some(Color::Ansi256(1))),
+            ColorScheme::Green => cs.set_fg(Some(Color::Ansi256(34))),
+            ColorScheme::Blue => cs.set_fg(Some(Color::Ansi256(61))),
+            ColorScheme::White => cs.set_fg(Some(Color::Ansi256(236))),
+            ColorScheme::Custom(theme) => &theme.section,
         };
-        stdout.reset()?;
-        Ok(())
+
+        write!(out, "----- ")?;
+        self.write_colored(out, op_name, name_spec)?;
+        writeln!(out, " {}", line)
     }
 
     /// represents a note label like ``NOTE: `` in ``NOTE: recognize this Unicode codepoint``
-    pub fn note_label(&self, label: &str) -> io::Result<()> {
-        if self == &ColorScheme::NoColors {
-            print!("{}", label);
-            return Ok(());
-        }
-
-        let mut stderr = StandardStream::stderr(self.color_choice());
+    pub fn note_label(&self, out: &mut dyn WriteColor, label: &str) -> io::Result<()> {
         let mut cs = ColorSpec::new();
-        stderr.set_color(match self {
+        self.write_colored(out, label, match self {
             ColorScheme::NoColors => &cs,
-            ColorScheme::Default => cs.set_fg(Some(Color::Magenta)),
+            ColorScheme::Auto | ColorScheme::Default => cs.set_fg(Some(Color::Magenta)),
             ColorScheme::RegularAndBold => cs.set_bold(true),
             ColorScheme::Red => cs.set_fg(Some(Color::Ansi256(218))),
             ColorScheme::Green => cs.set_fg(Some(Color::Ansi256(70))),
             ColorScheme::Blue => cs.set_fg(Some(Color::Ansi256(26))),
             ColorScheme::White => cs.set_fg(Some(Color::Ansi256(243))),
-        })?;
-        write!(&mut stderr, "{}", label)?;
-        stderr.reset()?;
-        Ok(())
+            ColorScheme::Custom(theme) => &theme.note,
+        })
     }
 
     /// represents a error label like ``ERROR: `` in ``ERROR: argument invalid``
-    pub fn error_label(&self, label: &str) -> io::Result<()> {
-        if self == &ColorScheme::NoColors {
-            eprint!("{}", label);
-            return Ok(());
-        }
-
-        let mut stderr = StandardStream::stderr(self.color_choice());
+    pub fn error_label(&self, out: &mut dyn WriteColor, label: &str) -> io::Result<()> {
         let mut cs = ColorSpec::new();
-        stderr.set_color(match self {
+        self.write_colored(out, label, match self {
             ColorScheme::NoColors => &cs,
-            ColorScheme::Default => cs.set_fg(Some(Color::Red)),
+            ColorScheme::Auto | ColorScheme::Default => cs.set_fg(Some(Color::Red)),
             ColorScheme::RegularAndBold => cs.set_bold(true),
             ColorScheme::Red => cs.set_fg(Some(Color::Ansi256(9))),
             ColorScheme::Green => cs.set_fg(Some(Color::Ansi256(46))),
             ColorScheme::Blue => cs.set_fg(Some(Color::Ansi256(27))),
             ColorScheme::White => cs.set_fg(Some(Color::Ansi256(232))),
+            ColorScheme::Custom(theme) => &theme.error,
         })?;
-        write!(&mut stderr, "{}", label)?;
-        stderr.flush()?;
-        stderr.reset()?;
-        Ok(())
+        out.flush()
     }
 
     /// represents a keyword like a column header or type name
-    pub fn keyword(&self, word: &str) -> io::Result<()> {
-        if self == &ColorScheme::NoColors {
-            print!("{}", word);
-            return Ok(());
-        }
-
-        let mut stdout = StandardStream::stdout(self.color_choice());
+    pub fn keyword(&self, out: &mut dyn WriteColor, word: &str) -> io::Result<()> {
         let mut cs = ColorSpec::new();
-        stdout.set_color(match self {
+        self.write_colored(out, word, match self {
             ColorScheme::NoColors => &cs,
-            ColorScheme::Default => cs.set_fg(Some(Color::White)),
+            ColorScheme::Auto | ColorScheme::Default => cs.set_fg(Some(Color::White)),
             ColorScheme::RegularAndBold => cs.set_bold(true),
             ColorScheme::Red => cs.set_fg(Some(Color::Ansi256(88))),
             ColorScheme::Green => cs.set_fg(Some(Color::Ansi256(36))),
             ColorScheme::Blue => cs.set_fg(Some(Color::Ansi256(147))),
             ColorScheme::White => cs.set_fg(Some(Color::Ansi256(240))),
-        })?;
-        write!(&mut stdout, "{}", word)?;
-        stdout.reset()?;
-        Ok(())
+            ColorScheme::Custom(theme) => &theme.keyword,
+        })
     }
 
-    pub fn outer_wrapper(&self, wrapper: &str) -> io::Result<()> {
-        if self == &ColorScheme::NoColors {
-            print!("{}", wrapper);
-            return Ok(());
-        }
-
-        let mut stdout = StandardStream::stdout(self.color_choice());
+    pub fn outer_wrapper(&self, out: &mut dyn WriteColor, wrapper: &str) -> io::Result<()> {
         let mut cs = ColorSpec::new();
-        stdout.set_color(match self {
+        self.write_colored(out, wrapper, match self {
             ColorScheme::NoColors => &cs,
-            ColorScheme::Default => cs.set_bold(true),
+            ColorScheme::Auto | ColorScheme::Default => cs.set_bold(true),
             ColorScheme::RegularAndBold => cs.set_bold(true),
             ColorScheme::Red => cs.set_fg(Some(Color::Ansi256(216))),
             ColorScheme::Green => cs.set_fg(Some(Color::Ansi256(190))),
             ColorScheme::Blue => cs.set_fg(Some(Color::Ansi256(45))),
             ColorScheme::White => cs.set_fg(Some(Color::Ansi256(246))),
-        })?;
-        write!(&mut stdout, "{}", wrapper)?;
-        stdout.reset()?;
-        Ok(())
+            ColorScheme::Custom(theme) => &theme.outer_wrapper,
+        })
     }
 
-    pub fn outer_separator(&self, sep: &str) -> io::Result<()> {
-        if self == &ColorScheme::NoColors {
-            print!("{}", sep);
-            return Ok(());
-        }
-
-        let mut stdout = StandardStream::stdout(self.color_choice());
+    pub fn outer_separator(&self, out: &mut dyn WriteColor, sep: &str) -> io::Result<()> {
         let mut cs = ColorSpec::new();
-        stdout.set_color(match self {
+        self.write_colored(out, sep, match self {
             ColorScheme::NoColors => &cs,
-            ColorScheme::Default => cs.set_bold(true),
+            ColorScheme::Auto | ColorScheme::Default => cs.set_bold(true),
             ColorScheme::RegularAndBold => cs.set_bold(true),
             ColorScheme::Red => cs.set_fg(Some(Color::Ansi256(209))),
             ColorScheme::Green => cs.set_fg(Some(Color::Ansi256(192))),
             ColorScheme::Blue => cs.set_fg(Some(Color::Ansi256(117))),
             ColorScheme::White => cs.set_fg(Some(Color::Ansi256(243))),
-        })?;
-        write!(&mut stdout, "{}", sep)?;
-        stdout.reset()?;
-        Ok(())
+            ColorScheme::Custom(theme) => &theme.outer_separator,
+        })
     }
 
-
-    pub fn inner_wrapper(&self, wrapper: &str) -> io::Result<()> {
-        if self == &ColorScheme::NoColors {
-            print!("{}", wrapper);
-            return Ok(());
-        }
-
-        let mut stdout = StandardStream::stdout(self.color_choice());
+    pub fn inner_wrapper(&self, out: &mut dyn WriteColor, wrapper: &str) -> io::Result<()> {
         let mut cs = ColorSpec::new();
-        stdout.set_color(match self {
+        self.write_colored(out, wrapper, match self {
             ColorScheme::NoColors => &cs,
-            ColorScheme::Default => cs.set_bold(true).set_fg(Some(Color::Blue)),
+            ColorScheme::Auto | ColorScheme::Default => cs.set_bold(true).set_fg(Some(Color::Blue)),
             ColorScheme::RegularAndBold => cs.set_bold(true),
             ColorScheme::Red => cs.set_fg(Some(Color::Ansi256(131))),
             ColorScheme::Green => cs.set_fg(Some(Color::Ansi256(118))),
             ColorScheme::Blue => cs.set_fg(Some(Color::Ansi256(33))),
             ColorScheme::White => cs.set_fg(Some(Color::Ansi256(255))),
-        })?;
-        write!(&mut stdout, "{}", wrapper)?;
-        stdout.reset()?;
-        Ok(())
+            ColorScheme::Custom(theme) => &theme.inner_wrapper,
+        })
     }
 
-    pub fn inner_separator(&self, sep: &str) -> io::Result<()> {
-        if self == &ColorScheme::NoColors {
-            print!("{}", sep);
-            return Ok(());
-        }
-
-        let mut stdout = StandardStream::stdout(self.color_choice());
+    pub fn inner_separator(&self, out: &mut dyn WriteColor, sep: &str) -> io::Result<()> {
         let mut cs = ColorSpec::new();
-        stdout.set_color(match self {
+        self.write_colored(out, sep, match self {
             ColorScheme::NoColors => &cs,
-            ColorScheme::Default => cs.set_bold(true).set_fg(Some(Color::Green)),
+            ColorScheme::Auto | ColorScheme::Default => cs.set_bold(true).set_fg(Some(Color::Green)),
             ColorScheme::RegularAndBold => cs.set_bold(true),
             ColorScheme::Red => cs.set_fg(Some(Color::Ansi256(160))),
             ColorScheme::Green => cs.set_fg(Some(Color::Ansi256(120))),
             ColorScheme::Blue => cs.set_fg(Some(Color::Ansi256(105))),
             ColorScheme::White => cs.set_fg(Some(Color::Ansi256(252))),
-        })?;
-        write!(&mut stdout, "{}", sep)?;
-        stdout.reset()?;
-        Ok(())
+            ColorScheme::Custom(theme) => &theme.inner_separator,
+        })
     }
 }
 
@@ -455,6 +789,7 @@ impl fmt::Display for ColorScheme {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use ColorScheme::*;
         match self {
+            Auto => f.write_str("Auto"),
             NoColors => f.write_str("None"),
             Default => f.write_str("Default"),
             RegularAndBold => f.write_str("RegularAndBold"),
@@ -462,6 +797,7 @@ impl fmt::Display for ColorScheme {
             Green => f.write_str("Green"),
             Blue => f.write_str("Blue"),
             White => f.write_str("White"),
+            Custom(theme) => f.write_str(theme.name()),
         }
     }
 }
@@ -480,21 +816,202 @@ pub enum Syntax {
     Kotlin,
     Perl,
     Rust,
+    /// a JSON document per output
+    Json,
+    /// a JSON document per output on a single line, in particular one line per operation if all ops are run
+    JsonLines,
+    /// comma-separated values according to RFC 4180 with a header row
+    Csv,
+    /// tab-separated values with a header row, escaping tab, newline, carriage return, and backslash as `\t`, `\n`, `\r`, and `\\`
+    Tsv,
+    /// GitHub-flavored markdown tables
+    Markdown,
+    /// HTML fragments with tables and lists
+    Html,
+    /// LaTeX tabular environments
+    Latex,
 }
 
 impl Syntax {
+    /// Take a name and return the corresponding Syntax instance (or None, if unknown)
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "c" => Some(Syntax::C),
+            "c++" | "cpp" => Some(Syntax::Cpp),
+            "csv" => Some(Syntax::Csv),
+            "golang" | "go" => Some(Syntax::Golang),
+            "html" => Some(Syntax::Html),
+            "human" | "default" => Some(Syntax::Human),
+            "java" => Some(Syntax::Java),
+            "json" => Some(Syntax::Json),
+            "jsonl" | "jsonlines" => Some(Syntax::JsonLines),
+            "kotlin" => Some(Syntax::Kotlin),
+            "latex" | "tex" => Some(Syntax::Latex),
+            "markdown" | "md" => Some(Syntax::Markdown),
+            "perl" => Some(Syntax::Perl),
+            "python" | "py" => Some(Syntax::Python),
+            "rust" | "rustlang" => Some(Syntax::Rust),
+            "tsv" => Some(Syntax::Tsv),
+            _ => None,
+        }
+    }
+
     /// A human-readable representation of this syntax name
     pub fn represent(&self) -> &'static str {
         match self {
             Syntax::C => "C",
             Syntax::Cpp => "C++",
+            Syntax::Csv => "CSV",
             Syntax::Golang => "golang",
+            Syntax::Html => "HTML",
             Syntax::Human => "human",
             Syntax::Java => "Java",
+            Syntax::Json => "JSON",
+            Syntax::JsonLines => "JSON lines",
             Syntax::Kotlin => "Kotlin",
+            Syntax::Latex => "LaTeX",
+            Syntax::Markdown => "markdown",
             Syntax::Perl => "perl",
             Syntax::Python => "python",
             Syntax::Rust => "rust",
+            Syntax::Tsv => "TSV",
+        }
+    }
+
+    /// The canonical name of this syntax accepted by `Syntax::by_name`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Syntax::C => "c",
+            Syntax::Cpp => "cpp",
+            Syntax::Csv => "csv",
+            Syntax::Golang => "golang",
+            Syntax::Html => "html",
+            Syntax::Human => "human",
+            Syntax::Java => "java",
+            Syntax::Json => "json",
+            Syntax::JsonLines => "jsonl",
+            Syntax::Kotlin => "kotlin",
+            Syntax::Latex => "latex",
+            Syntax::Markdown => "markdown",
+            Syntax::Perl => "perl",
+            Syntax::Python => "python",
+            Syntax::Rust => "rust",
+            Syntax::Tsv => "tsv",
+        }
+    }
+
+    /// Returns the entire list of supported syntaxes as a slice
+    pub fn all_as_slice() -> &'static [Syntax] {
+        &[
+            Syntax::Human,
+            Syntax::C,
+            Syntax::Cpp,
+            Syntax::Csv,
+            Syntax::Golang,
+            Syntax::Html,
+            Syntax::Java,
+            Syntax::Json,
+            Syntax::JsonLines,
+            Syntax::Kotlin,
+            Syntax::Latex,
+            Syntax::Markdown,
+            Syntax::Perl,
+            Syntax::Python,
+            Syntax::Rust,
+            Syntax::Tsv,
+        ]
+    }
+}
+
+/// The order in which the entries of an association are represented
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq)]
+pub enum AssociationOrder {
+    /// the order in which the operation generated the entries
+    #[default]
+    Insertion,
+    /// sorted by key
+    Key,
+    /// sorted by value
+    Value,
+}
+
+impl AssociationOrder {
+    /// Take a name and return the corresponding AssociationOrder instance (or None, if unknown)
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "insertion" => Some(AssociationOrder::Insertion),
+            "key" | "keys" => Some(AssociationOrder::Key),
+            "value" | "values" => Some(AssociationOrder::Value),
+            _ => None,
+        }
+    }
+
+    /// A human-readable representation of this order
+    pub fn represent(&self) -> &'static str {
+        match self {
+            AssociationOrder::Insertion => "insertion",
+            AssociationOrder::Key => "key",
+            AssociationOrder::Value => "value",
+        }
+    }
+
+    /// Returns the entire list of supported orders as a slice
+    pub fn all_as_slice() -> &'static [AssociationOrder] {
+        &[AssociationOrder::Insertion, AssociationOrder::Key, AssociationOrder::Value]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overwrite_with_file() {
+        let filepath = env::temp_dir().join(format!("opstr-test-config-{}.toml", std::process::id()));
+        fs::write(&filepath, "radix = 16\nsyntax = \"json\"\n[op_defaults]\ncenter = [\"\", \"80\"]\n").unwrap();
+
+        let mut conf = Configuration::default();
+        conf.overwrite_with_file(&filepath).unwrap();
+        conf.overwrite_with_clap(Some(2), None, None, None, None, None, None, None).unwrap();
+        fs::remove_file(&filepath).unwrap();
+
+        assert_eq!(conf.radix, 2);
+        assert_eq!(conf.sources.get("radix"), Some(&Source::CommandLine("radix")));
+        assert_eq!(conf.syntax, Syntax::Json);
+        assert_eq!(conf.sources.get("syntax"), Some(&Source::File(filepath)));
+
+        let args = Args::from(&[Arg::from_str("hi", 0)]);
+        assert_eq!(conf.args_with_op_defaults("center", &args), Args::from(&[Arg::from_str("hi", 0), Arg::from_str("80", 1)]));
+        assert_eq!(conf.args_with_op_defaults("repeat", &args), args);
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let filepath = env::temp_dir().join(format!("opstr-test-invalid-config-{}.toml", std::process::id()));
+        fs::write(&filepath, "radix = 7\n").unwrap();
+
+        let mut conf = Configuration::default();
+        assert!(conf.overwrite_with_file(&filepath).is_err());
+        fs::remove_file(&filepath).unwrap();
+        assert!(conf.overwrite_with_file(&filepath).is_err());
+    }
+
+    #[test]
+    fn test_color_choice() {
+        assert_eq!(ColorScheme::default(), ColorScheme::Auto);
+        assert_eq!(ColorScheme::by_name("auto"), Some(ColorScheme::Auto));
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(n, _)| *n == name).map(|(_, v)| OsString::from(v))
+        };
+        for scheme in [ColorScheme::Auto, ColorScheme::Red, ColorScheme::Default] {
+            assert_eq!(scheme.color_choice_with(true, env(&[])), ColorChoice::AlwaysAnsi);
+            assert_eq!(scheme.color_choice_with(false, env(&[])), ColorChoice::Never);
+            assert_eq!(scheme.color_choice_with(true, env(&[("NO_COLOR", "1")])), ColorChoice::Never);
+            assert_eq!(scheme.color_choice_with(true, env(&[("TERM", "dumb")])), ColorChoice::Never);
+            assert_eq!(scheme.color_choice_with(false, env(&[("CLICOLOR_FORCE", "1")])), ColorChoice::AlwaysAnsi);
+            assert_eq!(scheme.color_choice_with(false, env(&[("CLICOLOR_FORCE", "0")])), ColorChoice::Never);
         }
+        assert_eq!(ColorScheme::NoColors.color_choice_with(true, env(&[("CLICOLOR_FORCE", "1")])), ColorChoice::Never);
     }
 }
@@ -5,6 +5,8 @@ use std::io;
 
 use crate::range::Range;
 
+use serde_json::json;
+
 #[cfg(feature = "icu")]
 use icu::locid;
 #[cfg(feature = "icu")]
@@ -32,23 +34,148 @@ pub enum LibError {
     /// The user-provided operation name is unknown;
     /// specified by the op name.
     UnknownOp(String),
+    /// The output of one operation in a pipeline cannot be passed on to the next operation;
+    /// specified by (zero-based pipeline stage receiving the output, error message).
+    PipelineError(usize, String),
     /// Internal error, where generated data does not satisfy required format;
     /// specified by an error message.
     InvalidData(String),
     /// internal error related to I/O; specified by an error message.
     IOError(String),
+    /// A configuration file cannot be read or contains invalid settings;
+    /// specified by (filepath, error message).
+    ConfigFileError(String, String),
     /// The locale is not properly set up. A common case is that the locale data file
     /// does not contain necessary data to run the requested operation;
     /// specified by an error message.
     LocaleError(String),
+    /// An operation rejected a positional argument for a reason specific to this operation,
+    /// e.g. "regex-search" receives an invalid regular expression;
+    /// specified by (zero-based argument ID, op-specific reason code, error message)
+    OpError(usize, &'static str, String),
+    /// An operation was abandoned, because it exceeded its time limit or panicked;
+    /// specified by (op name, reason code, error message).
+    OpAborted(String, &'static str, String),
+}
+
+/// Errors are grouped into classes, which determine the exit code of the process
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// The program was invoked incorrectly, e.g. with an unknown flag value or operation name
+    Usage,
+    /// The arguments provided cannot be processed by the operation
+    Data,
+    /// Something failed which the user cannot fix by changing the invocation
+    Internal,
+}
+
+impl ErrorClass {
+    /// Stable name of this class used in machine-readable errors
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorClass::Usage => "usage",
+            ErrorClass::Data => "data",
+            ErrorClass::Internal => "internal",
+        }
+    }
+
+    /// Exit code of the process if it terminates with an error of this class
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ErrorClass::Usage => 2,
+            ErrorClass::Data => 3,
+            ErrorClass::Internal => 4,
+        }
+    }
+}
+
+impl LibError {
+    /// Stable error code identifying the variant, e.g. ``arg-value``.
+    /// Codes are never changed or reused, such that tools can rely on them.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::CLIValueError(_, _) => "cli-value",
+            Self::CLITypeError(_, _) => "cli-type",
+            Self::ArgValueError(_, _) => "arg-value",
+            Self::ArgTypeError(_, _) => "arg-type",
+            Self::ArgumentCountError(_, _, _) => "argument-count",
+            Self::UnknownOp(_) => "unknown-op",
+            Self::PipelineError(_, _) => "pipeline",
+            Self::InvalidData(_) => "invalid-data",
+            Self::IOError(_) => "io",
+            Self::ConfigFileError(_, _) => "config-file",
+            Self::LocaleError(_) => "locale",
+            Self::OpError(_, _, _) => "op-error",
+            Self::OpAborted(_, _, _) => "op-aborted",
+        }
+    }
+
+    /// The class of this error
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            Self::CLIValueError(_, _) | Self::CLITypeError(_, _) | Self::ArgumentCountError(_, _, _)
+                | Self::UnknownOp(_) | Self::ConfigFileError(_, _) | Self::LocaleError(_) => ErrorClass::Usage,
+            Self::ArgValueError(_, _) | Self::ArgTypeError(_, _) | Self::OpError(_, _, _)
+                | Self::PipelineError(_, _) => ErrorClass::Data,
+            Self::InvalidData(_) | Self::IOError(_) | Self::OpAborted(_, _, _) => ErrorClass::Internal,
+        }
+    }
+
+    /// The zero-based index of the positional argument this error refers to (if any)
+    pub fn arg_index(&self) -> Option<usize> {
+        match self {
+            Self::ArgValueError(index, _) | Self::ArgTypeError(index, _) | Self::OpError(index, _, _) => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// The name of the CLI flag (without leading dashes) this error refers to (if any)
+    pub fn flag(&self) -> Option<&'static str> {
+        match self {
+            Self::CLIValueError(name, _) | Self::CLITypeError(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The op-specific reason code further distinguishing `OpError` and `OpAborted`, e.g. ``timeout``.
+    /// Like `code`, reason codes are stable, but only unique within one operation.
+    pub fn reason(&self) -> Option<&'static str> {
+        match self {
+            Self::OpError(_, reason, _) | Self::OpAborted(_, reason, _) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Represent this error as JSON object on a single line with its code, class, message,
+    /// argument index, CLI flag, and op-specific reason. `op_name` is the operation which was requested (if any).
+    pub fn represent_json(&self, op_name: Option<&str>) -> String {
+        serde_json::Value::Object(self.to_json(op_name)).to_string()
+    }
+
+    /// The members of the JSON object of `represent_json`, such that responses of `serve` and `serve_http`
+    /// can report errors in the same shape
+    pub(crate) fn to_json(&self, op_name: Option<&str>) -> serde_json::Map<String, serde_json::Value> {
+        let json = json!({
+            "code": self.code(),
+            "class": self.class().name(),
+            "message": self.to_string(),
+            "arg_index": self.arg_index(),
+            "flag": self.flag(),
+            "reason": self.reason(),
+            "op": op_name,
+        });
+        match json {
+            serde_json::Value::Object(members) => members,
+            _ => unreachable!("json! of an object literal returns an object"),
+        }
+    }
 }
 
 #[cfg(feature = "icu")]
 impl From<locid::ParserError> for LibError {
     fn from(err: locid::ParserError) -> Self {
-        let mut msg = String::from("ICU parsing error: ");
-        msg.push_str(&err.to_string());
-        LibError::IOError(msg)
+        LibError::CLIValueError("locale", format!("not a valid locale identifier: {}", err))
     }
 }
 
@@ -80,15 +207,47 @@ impl fmt::Display for LibError {
             Self::CLIValueError(name, errmsg) => write!(f, "invalid CLI argument for '--{}': {}", name, errmsg),
             Self::CLITypeError(name, errmsg) => write!(f, "invalid type for '--{}': {}", name, errmsg),
             Self::ArgTypeError(position, errmsg) => write!(f, "invalid type for argument #{}: {}", position + 1, errmsg),
-            Self::ArgValueError(position, errmsg) => write!(f, "invalid CLI argument for #{}: {}", position + 1, errmsg),
+            Self::ArgValueError(position, errmsg) | Self::OpError(position, _, errmsg) => write!(f, "invalid CLI argument for #{}: {}", position + 1, errmsg),
             Self::ArgumentCountError(expected, actual, opt_errmsg) => match opt_errmsg {
                 Some(msg) => write!(f, "invalid number of CLI arguments, expected {} got {}; provide these arguments: {}", expected, actual, msg),
                 None => write!(f, "invalid number of CLI arguments, expected {} got {}", expected, actual),
             },
             Self::UnknownOp(op) => write!(f, "unknown operation '{}'", op),
+            Self::PipelineError(stage, errmsg) => write!(f, "invalid input for pipeline stage #{}: {}", stage + 1, errmsg),
             Self::InvalidData(msg) => write!(f, "internal data error: {}", msg),
             Self::IOError(msg) => write!(f, "I/O error: {}", msg),
+            Self::ConfigFileError(path, msg) => write!(f, "invalid configuration file '{}': {}", path, msg),
             Self::LocaleError(msg) => write!(f, "error using the locale: {}", msg),
+            Self::OpAborted(op, _, errmsg) => write!(f, "operation '{}' was aborted: {}", op, errmsg),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_represent_json() {
+        let err = LibError::ArgValueError(1, "expected an integer, got \"x\"".to_owned());
+        assert_eq!(err.class().exit_code(), 3);
+        assert_eq!(
+            err.represent_json(Some("repeat")),
+            r#"{"code":"arg-value","class":"data","message":"invalid CLI argument for #2: expected an integer, got \"x\"","arg_index":1,"flag":null,"reason":null,"op":"repeat"}"#
+        );
+
+        let err = LibError::CLIValueError("radix", "Only radices 2, 10, and 16 are supported".to_owned());
+        assert_eq!((err.code(), err.flag(), err.class()), ("cli-value", Some("radix"), ErrorClass::Usage));
+        assert!(err.represent_json(None).ends_with(r#""arg_index":null,"flag":"radix","reason":null,"op":null}"#));
+
+        let err = LibError::OpError(1, "negative-count", "cannot repeat -1 (negative) times".to_owned());
+        assert_eq!((err.code(), err.reason(), err.arg_index(), err.class()), ("op-error", Some("negative-count"), Some(1), ErrorClass::Data));
+    }
+
+    #[cfg(feature = "icu")]
+    #[test]
+    fn test_invalid_locale() {
+        let err: LibError = "en-US-@".parse::<locid::Locale>().unwrap_err().into();
+        assert_eq!((err.code(), err.flag(), err.class().exit_code()), ("cli-value", Some("locale"), 2));
+    }
+}
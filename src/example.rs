@@ -0,0 +1,55 @@
+//! Worked examples documenting how an operation behaves.
+//!
+//! Every example stores the arguments and the output expected when running the operation
+//! with the default `Configuration`. `--help-op` shows them and a test runs them to verify
+//! that they still produce the documented result.
+
+use crate::input::{Arg, Args};
+use crate::output::Output;
+
+/// Arguments provided to an operation and the output it returns
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+    /// arguments as given on the command line
+    pub args: Vec<&'static str>,
+    /// the output expected, where notes are not compared
+    pub output: Output,
+}
+
+impl Example {
+    /// Create an example where running the operation with `args` returns `output`
+    pub fn new(args: &[&'static str], output: Output) -> Self {
+        Self { args: args.to_vec(), output }
+    }
+
+    /// Return the arguments of this example as `Args`
+    pub fn to_args(&self) -> Args {
+        Args::from(&self.args.iter().enumerate().map(|(i, arg)| Arg::from_str(arg, i)).collect::<Vec<Arg>>())
+    }
+
+    /// Does `actual` have the same data as the expected output? Notes are disregarded.
+    pub fn matches(&self, actual: &Output) -> bool {
+        let mut actual = actual.clone();
+        actual.clear_notes();
+        let mut expected = self.output.clone();
+        expected.clear_notes();
+        actual == expected
+    }
+
+    /// Return the command line running operation `op_name` with the arguments of this example
+    pub fn command_line(&self, op_name: &str) -> String {
+        let mut words = vec!["opstr".to_owned(), "--op".to_owned(), op_name.to_owned()];
+        words.extend(self.args.iter().map(|arg| shell_quote(arg)));
+        words.join(" ")
+    }
+}
+
+/// Quote `word` for a POSIX shell unless it consists of harmless characters only
+pub(crate) fn shell_quote(word: &str) -> String {
+    let harmless = |c: char| c.is_alphanumeric() || "_-+=.,/:@%".contains(c);
+    if !word.is_empty() && word.chars().all(harmless) {
+        word.to_owned()
+    } else {
+        format!("'{}'", word.replace('\'', "'\\''"))
+    }
+}
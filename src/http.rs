@@ -0,0 +1,146 @@
+//! A local HTTP API to run operations without spawning a process per call.
+//!
+//! * ``GET /ops`` returns all operations as returned by `list_all_ops`
+//! * ``POST /ops/{name}`` runs operation {name}
+//! * ``POST /guess`` runs all matching operations like `run_matching_ops`
+//!
+//! Both POST endpoints expect a JSON body like ``{"args": ["hello"], "config": {"radix": 16}}``,
+//! where `config` is optional and accepts the same settings as requests of `serve`.
+//! Responses are JSON documents built from `Output`. Errors are returned as JSON object of `LibError::represent_json`
+//! with an HTTP status code corresponding to the `LibError`.
+
+use std::io::Write;
+
+use crate::config::Configuration;
+use crate::errors::LibError;
+use crate::input::{Arg, Args};
+use crate::matcher::json_op_result;
+use crate::registry::Registry;
+use crate::serve::RequestConfig;
+
+use serde_json::json;
+
+/// The body of a POST request
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RequestBody {
+    #[serde(default)]
+    args: Vec<String>,
+    #[serde(default)]
+    config: RequestConfig,
+}
+
+/// Return the HTTP status code corresponding to `err`
+fn status_code(err: &LibError) -> u16 {
+    match err {
+        LibError::UnknownOp(_) => 404,
+        LibError::CLIValueError(_, _) | LibError::CLITypeError(_, _) => 400,
+        LibError::ArgValueError(_, _) | LibError::ArgTypeError(_, _) | LibError::OpError(_, _, _) | LibError::ArgumentCountError(_, _, _) | LibError::PipelineError(_, _) => 422,
+        LibError::OpAborted(_, _, _) => 503,
+        _ => 500,
+    }
+}
+
+/// Represent `err` for the requested operation `op_name` as response with its status code
+fn error_response(err: &LibError, op_name: Option<&str>) -> (u16, String) {
+    (status_code(err), err.represent_json(op_name))
+}
+
+/// Parse the JSON `body` of a POST request into arguments and the configuration to use
+fn parse_body(conf: &Configuration, body: &str) -> Result<(Args, Configuration), LibError> {
+    let request: RequestBody = match serde_json::from_str(body) {
+        Ok(r) => r,
+        Err(e) => return Err(LibError::CLIValueError("http", format!("invalid request body: {}", e))),
+    };
+    let args = Args::from(&request.args.iter().enumerate().map(|(i, arg)| Arg::from_str(arg, i)).collect::<Vec<Arg>>());
+    Ok((args, request.config.apply(conf)?))
+}
+
+/// Answer a request with `method` for `url` and return the status code and JSON body of the response
+pub(crate) fn handle(registry: &Registry, conf: &Configuration, method: &str, url: &str, body: &str) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default();
+
+    match (method, path) {
+        ("GET", "/ops") => (200, registry.list_all_ops(conf).represent_json(conf)),
+        ("POST", "/guess") => match parse_body(conf, body) {
+            Ok((args, conf)) => {
+                let results = registry.evaluate_matching_ops(&conf, &args).iter()
+                    .map(|(op_name, priority, result)| json_op_result(&conf, op_name, *priority, result))
+                    .collect::<Vec<serde_json::Value>>();
+                (200, json!(results).to_string())
+            },
+            Err(e) => error_response(&e, None),
+        },
+        ("POST", _) if path.starts_with("/ops/") => {
+            let requested = &path["/ops/".len()..];
+            let result = parse_body(conf, body)
+                .and_then(|(args, conf)| registry.run_op(&conf, &args, requested).map(|(name, output)| (name, output, conf)));
+            match result {
+                Ok((op_name, output, conf)) => (200, json!({"op": op_name, "result": output.reduce(&conf).to_json(&conf)}).to_string()),
+                Err(e) => error_response(&e, Some(requested)),
+            }
+        },
+        _ if path == "/ops" || path == "/guess" || path.starts_with("/ops/") => {
+            (405, LibError::CLIValueError("http", format!("method {} is not allowed for {}", method, path)).represent_json(None))
+        },
+        _ => (404, LibError::CLIValueError("http", format!("no endpoint {}", path)).represent_json(None)),
+    }
+}
+
+/// Listen on `address` (like ``127.0.0.1:8080``) and answer HTTP requests with operations of `registry`
+/// until the process is terminated. Requests are answered one after another.
+pub fn serve_http(registry: &Registry, conf: &Configuration, address: &str) -> Result<(), LibError> {
+    let server = match tiny_http::Server::http(address) {
+        Ok(s) => s,
+        Err(e) => return Err(LibError::IOError(format!("cannot listen on '{}': {}", address, e))),
+    };
+
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let (status, json) = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => handle(registry, conf, request.method().as_str(), request.url(), &body),
+            Err(e) => (400, LibError::CLIValueError("http", format!("request body is not UTF-8: {}", e)).represent_json(None)),
+        };
+
+        let response = tiny_http::Response::from_string(json)
+            .with_status_code(status)
+            .with_header(tiny_http::Header::from_bytes("Content-Type", "application/json").expect("valid header"));
+        if let Err(e) = request.respond(response) {
+            let mut stderr = conf.color_scheme.stderr();
+            conf.color_scheme.error_label(&mut stderr, "ERROR")?;
+            writeln!(stderr, ": cannot send HTTP response: {}", e)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle() {
+        let conf = Configuration::default();
+        let registry = Registry::default();
+
+        let (status, body) = handle(&registry, &conf, "GET", "/ops", "");
+        assert_eq!(status, 200);
+        assert!(body.starts_with(r#"{"type":"association","data":[["#));
+
+        let (status, body) = handle(&registry, &conf, "POST", "/ops/count-codepoints", r#"{"args": ["äöü"]}"#);
+        assert_eq!((status, body.as_str()), (200, r#"{"op":"count-codepoints","result":{"type":"scalar","data":3,"notes":[]}}"#));
+
+        let (status, body) = handle(&registry, &conf, "POST", "/guess", r#"{"args": ["hello"]}"#);
+        assert_eq!(status, 200);
+        assert!(body.starts_with(r#"[{"op":"#));
+
+        let (status, body) = handle(&registry, &conf, "POST", "/ops/no-such-op", "{}");
+        assert_eq!((status, body), (404, LibError::UnknownOp("no-such-op".to_owned()).represent_json(Some("no-such-op"))));
+        assert_eq!(handle(&registry, &conf, "POST", "/ops/count-codepoints", r#"{"args": []}"#).0, 422);
+        assert_eq!(handle(&registry, &conf, "POST", "/ops/count-codepoints", "args").0, 400);
+        assert_eq!(handle(&registry, &conf, "POST", "/ops/count-codepoints", r#"{"args": ["a"], "config": {"radix": 7}}"#).0, 400);
+        assert_eq!(handle(&registry, &conf, "DELETE", "/ops", "").0, 405);
+        assert_eq!(handle(&registry, &conf, "GET", "/nothing", "").0, 404);
+    }
+}
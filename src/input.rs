@@ -6,6 +6,8 @@
 
 use std::slice;
 use crate::errors;
+use crate::output::{Output, OutputValue};
+use crate::range;
 
 /// An argument for an operation
 #[derive(Clone, Debug, Hash, PartialEq)]
@@ -13,7 +15,7 @@ pub enum Arg {
     /// A sequence of Unicode codepoints (acc. to Unicode) and the index of this argument.
     Chars(String, usize),
     /// A sequence of bytes (only recommended for non-Unicode content) and the index of this argument.
-    /// NOTE: bytes support is currently not implemented
+    /// Only some operations (e.g. digests) support bytes. The others return an `ArgTypeError`.
     Bytes(Vec<u8>, usize),
 }
 
@@ -24,11 +26,29 @@ impl Arg {
     }
 
     /// Construct a byteslice `Arg` given the provided bytes, and the zero-based index of this argument
-    #[doc(hidden)]
     pub fn from_bytes(arg: &[u8], arg_id: usize) -> Self {
         Self::Bytes( arg.to_owned(), arg_id )
     }
 
+    /// Construct a byteslice `Arg` given the hexadecimal representation of bytes (like ``0x68656C6C6F`` or ``68 65 6c``),
+    /// and the zero-based index of this argument
+    pub fn from_hex(arg: &str, arg_id: usize) -> Result<Self, errors::LibError> {
+        let digits = arg.strip_prefix("0x").unwrap_or(arg).chars().filter(|c| !c.is_whitespace()).collect::<Vec<char>>();
+        if digits.len() % 2 != 0 {
+            return Err(errors::LibError::ArgValueError(arg_id, "hexadecimal representation requires an even number of digits".to_owned()));
+        }
+
+        let mut bytes = vec![];
+        for pair in digits.chunks(2) {
+            match (pair[0].to_digit(16), pair[1].to_digit(16)) {
+                (Some(high), Some(low)) => bytes.push((16 * high + low) as u8),
+                _ => return Err(errors::LibError::ArgValueError(arg_id, format!("'{}{}' is not a hexadecimal representation of a byte", pair[0], pair[1]))),
+            }
+        }
+
+        Ok(Self::Bytes(bytes, arg_id))
+    }
+
     /// Return the string of this argument or panic
     pub fn str_or_panic(&self) -> &str {
         match self {
@@ -67,6 +87,18 @@ impl<'s> TryFrom<&'s Arg> for &'s str {
     }
 }
 
+impl<'s> TryFrom<&'s Arg> for &'s [u8] {
+    type Error = errors::LibError;
+
+    /// Return the bytes of this argument. A Unicode string is represented by its UTF-8 bytes.
+    fn try_from(value: &'s Arg) -> Result<Self, Self::Error> {
+        match value {
+            Arg::Chars(s, _idx) => Ok(s.as_bytes()),
+            Arg::Bytes(b, _idx) => Ok(b),
+        }
+    }
+}
+
 /// An ordered container for arguments
 #[derive(Clone, Debug, PartialEq)]
 pub struct Args {
@@ -97,6 +129,14 @@ impl Args {
         }
     }
 
+    /// Replace the (zero-based) index-th element by `arg` and return the replaced element, or return `Err` if it does not exist
+    pub fn replace(&mut self, index: usize, arg: Arg) -> Result<Arg, errors::LibError> {
+        match self.args.get_mut(index) {
+            Some(old_arg) => Ok(std::mem::replace(old_arg, arg)),
+            None => Err(errors::LibError::ArgumentCountError(range::Range::IndexOpen(index + 1), self.args.len(), None)),
+        }
+    }
+
     /// Return the (zero-based) index-th element or return the provided `default` `Arg`
     pub fn get_or_default<'s, 'd: 's>(&'s self, index: usize, default: &'d Arg) -> &Arg {
         match self.args.get(index) {
@@ -111,3 +151,69 @@ impl Args {
     }
 }
 
+impl TryFrom<&Output> for Args {
+    type Error = errors::LibError;
+
+    /// Convert the `Output` of an operation into `Args` for another operation.
+    /// A scalar becomes one argument, every list item becomes one argument.
+    /// A list of bytes becomes one bytes argument.
+    /// Associations and tables have no meaningful representation as argument sequence.
+    fn try_from(value: &Output) -> Result<Self, Self::Error> {
+        let to_arg = |(i, value): (usize, &OutputValue)| {
+            Arg::from_str(&match value {
+                OutputValue::Bool(b) => (if *b { "true" } else { "false" }).to_owned(),
+                OutputValue::Byte(b) => format!("{}", b),
+                OutputValue::Int(i) => format!("{}", i),
+                OutputValue::SingleLineText(s) | OutputValue::MultiLineText(s) => s.to_owned(),
+            }, i)
+        };
+
+        match value {
+            Output::HomogeneousList { data, .. } if !data.is_empty() && matches!(data[0], OutputValue::Byte(_)) => {
+                let bytes = data.iter().filter_map(|v| match v { OutputValue::Byte(b) => Some(*b), _ => None }).collect::<Vec<u8>>();
+                Ok(Args { args: vec![Arg::from_bytes(&bytes, 0)] })
+            },
+            Output::Scalar { data, .. } => Ok(Args { args: vec![to_arg((0, data))] }),
+            Output::HomogeneousList { data, .. } |
+            Output::HeterogeneousList { data, .. } => Ok(Args { args: data.iter().enumerate().map(to_arg).collect() }),
+            Output::Association { .. } => Err(errors::LibError::ArgTypeError(0, "an association cannot be converted into arguments".to_owned())),
+            Output::Table { .. } => Err(errors::LibError::ArgTypeError(0, "a table cannot be converted into arguments".to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args_from_output() {
+        let scalar = Output::from(42usize);
+        assert_eq!(Args::try_from(&scalar).unwrap(), Args::from(&[Arg::from_str("42", 0)]));
+
+        let list = Output::from_value_list(&[OutputValue::from_str("a"), OutputValue::Bool(true)], &[]);
+        assert_eq!(Args::try_from(&list).unwrap(), Args::from(&[Arg::from_str("a", 0), Arg::from_str("true", 1)]));
+
+        let bytes = Output::from_value_list(&[OutputValue::Byte(104), OutputValue::Byte(105)], &[]);
+        assert_eq!(Args::try_from(&bytes).unwrap(), Args::from(&[Arg::from_bytes(b"hi", 0)]));
+
+        let table = Output::Table { data: vec![], column_headers: vec![], notes: vec![] };
+        assert!(Args::try_from(&table).is_err());
+    }
+
+    #[test]
+    fn test_arg_from_hex() {
+        assert_eq!(Arg::from_hex("0x68656C6c6F", 2).unwrap(), Arg::from_bytes(b"hello", 2));
+        assert_eq!(Arg::from_hex("00 ff", 0).unwrap(), Arg::from_bytes(&[0, 255], 0));
+        assert!(Arg::from_hex("abc", 0).is_err());
+        assert!(Arg::from_hex("zz", 0).is_err());
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut args = Args::from(&[Arg::from_str("a", 0), Arg::from_str("b", 1)]);
+        assert_eq!(args.replace(1, Arg::from_str("c", 1)).unwrap(), Arg::from_str("b", 1));
+        assert_eq!(args, Args::from(&[Arg::from_str("a", 0), Arg::from_str("c", 1)]));
+        assert!(matches!(args.replace(2, Arg::from_str("d", 2)), Err(errors::LibError::ArgumentCountError(range::Range::IndexOpen(3), 2, None))));
+    }
+}
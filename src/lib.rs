@@ -5,6 +5,8 @@
 //! * `Configuration` represents how the output shall be represented and which locale shall be used for Unicode operations.
 //! * `LibError` is an `enum` of all possible error types that can occur
 //! * `Arg` represents one input argument (final content, in the case of stdin/file). `Args` is a sequence of them.
+//! * `Registry` holds the set of operations. `Registry::default()` contains all built-in operations.
+//!   Implement the `Op` trait and call `Registry::register` to add your own operations.
 //! 
 //! ## Concepts
 //! 
@@ -15,26 +17,46 @@
 //! 
 //! ## API
 //! 
+//! * All of the following functions use the built-in operations. Each of them is also available as method of `Registry`.
 //! * `list_all_ops` returns the list of supported operations
+//! * `search_ops` returns the list of operations matching keywords
+//! * `help_op` returns the description, usage, parameters and properties of one operation
 //! * `list_matching_ops` returns the list of possible operations for the provided arguments
 //! * `matcher::run_op` returns the `Output` after running the one operation specified
+//! * `matcher::run_ops_pipeline` returns the `Output` after running the operations specified one after another, feeding the `Output` of one operation as `Args` to the next one
+//! * `matcher::run_ops_per_line` runs the operations once per line of one argument and merges the `Output` of all runs
 //! * `matcher::run_matching_ops` runs all operations appropriate for the provided arguments and writes the result to stdout & stderr
+//! * `explain_ranking` explains which operations `run_matching_ops` runs in which order and why others are dropped
+//! * `repl` starts an interactive session which keeps arguments and configuration between runs;
+//!   `repl_stdin` reads from a terminal with a line editor completing operation names
+//! * `serve` answers JSON requests read line by line with one JSON response per line
+//! * `serve_http` answers HTTP requests to run operations (requires cargo feature `http`)
 //! 
 //! ## Notes
 //! 
 //! 1. The CLI output is always valid UTF-8. This might change in the future, but in the current release, this is the case.
-//! 2. `Arg` is either Unicode content (`Chars`) or an arbitrary byte sequence (`Bytes`). `Bytes` is not yet supported.
-//! 3. `Output` abstracts the type of result of an operation.
+//! 2. `Arg` is either Unicode content (`Chars`) or an arbitrary byte sequence (`Bytes`). Only some ops support `Bytes`.
+//! 3. `Output` abstracts the type of result of an operation. `Output::print` writes it to stdout & stderr, `Output::render_to` to any `termcolor::WriteColor` and `Output::to_string` returns it.
 //! 4. `Configuration.syntax` defines which formal grammar shall be used for representation. The default representation for humans does not have a specification.
 
 pub(crate) mod auxiliary;
 pub(crate) mod config;
 pub(crate) mod errors;
+pub(crate) mod example;
+#[cfg(feature = "http")]
+pub(crate) mod http;
 pub(crate) mod input;
 pub(crate) mod matcher;
 pub(crate) mod ops;
 pub(crate) mod range;
 pub(crate) mod output;
+pub(crate) mod parallel;
+pub(crate) mod params;
+pub(crate) mod registry;
+pub(crate) mod repl;
+pub(crate) mod serve;
+pub(crate) mod stream;
+pub(crate) mod theme;
 
 /// This is a binary blob generated with the icu4x library.
 /// It contains various locale-specific convention data.
@@ -47,11 +69,28 @@ pub(crate) const UNICODE_DATA: &str = include_str!("../data/UnicodeData.txt");
 
 pub use config::Configuration;
 pub use config::ColorScheme;
-pub use errors::LibError;
+pub use config::AssociationOrder;
+pub use config::Syntax;
+pub use errors::{ErrorClass, LibError};
+pub use example::Example;
 pub use input::{Arg, Args};
-pub use output::Output;
+pub use output::{Output, OutputValue};
+pub use params::{Param, ParamType};
+pub use range::Range;
+pub use theme::Theme;
+pub use registry::{Op, Registry};
 pub use matcher::list_all_ops;
+pub use matcher::search_ops;
+pub use matcher::help_op;
 pub use matcher::list_matching_ops;
 pub use matcher::list_color_schemes;
 pub use matcher::run_op;
+pub use matcher::run_op_streaming;
+pub use matcher::run_ops_pipeline;
+pub use matcher::run_ops_per_line;
 pub use matcher::run_matching_ops;
+pub use matcher::explain_ranking;
+pub use repl::{repl, repl_stdin};
+pub use serve::serve;
+#[cfg(feature = "http")]
+pub use http::serve_http;
@@ -7,12 +7,14 @@ use std::ffi::OsString;
 use std::path;
 use std::process;
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap::builder::PossibleValuesParser;
+use clap_complete::Shell;
 use opstr::Args;
 use opstr::Configuration;
 use opstr::LibError;
 
-use std::io::Read;
+use std::io::{Read, Write};
 use std::fs;
 
 /// Analyze strings and apply string operations
@@ -25,14 +27,47 @@ use std::fs;
 struct Opts {
     #[clap(short = 'p', long, help = "name of the operation to apply")]
     op: Option<String>,
+    #[clap(long, help = "name of an operation to apply to the output of the previous operation (can be repeated)")]
+    then: Vec<String>,
+    #[clap(long, help = "print nothing, but exit with status 0 if the boolean result is true, 1 if it is false, and 2 or higher on errors")]
+    exit_status: bool,
+    #[clap(long, help = "with --exit-status, print the output nevertheless")]
+    print_output: bool,
     #[clap(short, long, help = "provide the list of operations matching the provided keywords")]
     list_ops: bool,
+    #[clap(long, help = "with --list-ops, also show usage and number of arguments per operation")]
+    details: bool,
+    #[clap(long, value_name = "NAME", help = "describe operation NAME with its parameters and examples")]
+    help_op: Option<String>,
+    #[clap(long, help = "instead of running all matching operations, explain their priorities and why others are dropped")]
+    explain_ranking: bool,
+    #[clap(long, value_name = "PRIORITY", help = "if all matching operations are run, only run those with at least this priority (0.0 to 1.0)")]
+    min_priority: Option<f32>,
+    #[clap(long, value_name = "N", help = "if all matching operations are run, only run the N operations with the highest priority")]
+    top: Option<usize>,
+    #[clap(long, value_name = "SECONDS", help = "if all matching operations are run, abort operations taking longer than SECONDS (default: 10); aborted operations keep running in the background and once 16 of them pile up, remaining operations may be refused")]
+    timeout: Option<f32>,
     #[clap(long, help = "provide the list of selectable syntaxes")]
     list_syntax: bool,
+    #[clap(long, help = "start an interactive session keeping arguments & configuration (see ':help')")]
+    repl: bool,
+    #[clap(long, help = "read JSON requests like {\"op\": NAME, \"args\": [...]} line by line from stdin and write one JSON response per line")]
+    serve: bool,
+    #[cfg(feature = "http")]
+    #[clap(long, value_name = "ADDRESS", help = "answer HTTP requests at ADDRESS like 127.0.0.1:8080 (GET /ops, POST /ops/NAME, POST /guess)")]
+    http: Option<String>,
+    #[clap(long, help = "read settings from this configuration file (in addition to the user and project configuration files)")]
+    config: Option<path::PathBuf>,
+    #[clap(long, value_name = "SHELL", help = "print a completion script for SHELL (bash, zsh, fish, …) and terminate")]
+    completions: Option<Shell>,
+    #[clap(long, value_enum, value_name = "FORMAT", default_value_t = ErrorFormat::Human, help = "report errors as human-readable message or as JSON object on stderr")]
+    error_format: ErrorFormat,
     #[clap(long, help = "dump which arguments & configuration you consider and terminate (helpful for debugging)")]
     dump: bool,
     #[clap(long, help = "representation output syntax")]
     syntax: Option<String>,
+    #[clap(long, help = "order entries of associations by 'insertion' (default), 'key', or 'value'")]
+    association_order: Option<String>,
     #[clap(long, help = "use uppercase letters for representation of hexadecimal values")]
     hex_upper: Option<bool>,
     #[clap(long, help = "radix {2, 10, or 16} to use for integers")]
@@ -43,7 +78,7 @@ struct Opts {
     column: Option<String>,
     #[clap(long, help = "provide the list of selectable color schemes")]
     list_color_scheme: bool,
-    #[clap(long, help = "color scheme like none, default, or regularandbold")]
+    #[clap(long, help = "color scheme like auto, none, default, or regularandbold")]
     color_scheme: Option<String>,
     #[clap(long, help = "locale to be used for locale-dependent operations")]
     locale: Option<String>,
@@ -51,16 +86,140 @@ struct Opts {
     stdin_as_arg: Vec<usize>,
     #[clap(long, help = "interpret the argument with this one-based ID as filepath and insert its file content there")]
     file_as_arg: Vec<usize>,
+    #[clap(long, help = "interpret the argument with this one-based ID as filepath (or '-' for stdin) and insert its content as bytes there")]
+    bytes_as_arg: Vec<usize>,
+    #[clap(long, help = "interpret the argument with this one-based ID as hexadecimal representation of bytes")]
+    hex_as_arg: Vec<usize>,
+    #[clap(long, help = "run the operation once for every line of the argument with this one-based ID")]
+    per_line_as_arg: Option<usize>,
     #[clap(trailing_var_arg = true, allow_hyphen_values = true)]
     args: Vec<OsString>,
 }
 
-fn main() -> Result<(), LibError> {
-    // (1) Prepare configuration
+/// Generate a completion script for `shell` which also completes the names of operations,
+/// syntaxes, color schemes, and association orders as values of the corresponding flags
+fn print_completions(shell: Shell) {
+    let op_names = opstr::Registry::default().iter().map(|op| op.name()).collect::<Vec<&'static str>>();
+    let syntax_names = opstr::Syntax::all_as_slice().iter().map(|s| s.name()).collect::<Vec<&'static str>>();
+    let color_scheme_names = opstr::ColorScheme::all_as_slice().iter().map(|cs| cs.name()).collect::<Vec<&'static str>>();
+    let order_names = opstr::AssociationOrder::all_as_slice().iter().map(|o| o.represent()).collect::<Vec<&'static str>>();
+
+    // NOTE: possible values are only attached to the command used for generation,
+    //       such that the library still reports unknown names with its own error messages
+    let mut cmd = Opts::command()
+        .mut_arg("op", |arg| arg.value_parser(PossibleValuesParser::new(op_names.clone())))
+        .mut_arg("help_op", |arg| arg.value_parser(PossibleValuesParser::new(op_names.clone())))
+        .mut_arg("then", |arg| arg.value_parser(PossibleValuesParser::new(op_names)))
+        .mut_arg("syntax", |arg| arg.value_parser(PossibleValuesParser::new(syntax_names)))
+        .mut_arg("color_scheme", |arg| arg.value_parser(PossibleValuesParser::new(color_scheme_names)))
+        .mut_arg("association_order", |arg| arg.value_parser(PossibleValuesParser::new(order_names)));
+
+    clap_complete::generate(shell, &mut cmd, "opstr", &mut std::io::stdout());
+}
+
+/// Where the single argument of an operation supporting streaming is read from
+enum StreamSource {
+    Stdin,
+    File(path::PathBuf),
+}
+
+/// If `opts` runs a single operation supporting streaming with one argument taken from stdin or a file,
+/// return where to stream it from. Otherwise the arguments are read into memory.
+fn streaming_source(opts: &Opts) -> Option<StreamSource> {
+    let op_name = opts.op.as_ref()?;
+    if opts.args.len() != 1 || !opts.then.is_empty() || opts.per_line_as_arg.is_some() || opts.repl || opts.serve || opts.dump || opts.list_ops || opts.help_op.is_some() {
+        return None;
+    }
+
+    let registry = opstr::Registry::default();
+    let op = registry.get(op_name)?;
+    if !op.streaming() {
+        return None;
+    }
+
+    if opts.stdin_as_arg.contains(&1) {
+        Some(StreamSource::Stdin)
+    } else if opts.file_as_arg.contains(&1) {
+        Some(StreamSource::File(path::PathBuf::from(&opts.args[0])))
+    } else if opts.bytes_as_arg.contains(&1) {
+        // string parameters must reject bytes arguments as usual
+        let accepts_bytes = op.params().first().map(|p| matches!(p.kind, opstr::ParamType::Bytes | opstr::ParamType::StringOrBytes)).unwrap_or(false);
+        match (accepts_bytes, opts.args[0].to_str()) {
+            (false, _) => None,
+            (true, Some("-")) => Some(StreamSource::Stdin),
+            (true, _) => Some(StreamSource::File(path::PathBuf::from(&opts.args[0]))),
+        }
+    } else {
+        None
+    }
+}
+
+/// Run operation `op_name` reading its single argument chunk by chunk from `source`
+fn run_streaming(conf: &Configuration, source: &StreamSource, op_name: &str) -> Result<(&'static str, opstr::Output), LibError> {
+    match source {
+        StreamSource::Stdin => opstr::run_op_streaming(conf, &mut std::io::stdin().lock(), op_name),
+        StreamSource::File(filepath) => {
+            if !filepath.exists() {
+                return Err(LibError::CLIValueError("file-as-arg", format!("file '{}' not found", filepath.display())));
+            }
+            opstr::run_op_streaming(conf, &mut fs::File::open(filepath)?, op_name)
+        },
+    }
+}
+
+/// How errors terminating the process are reported on stderr
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+enum ErrorFormat {
+    /// a message like "ERROR: unknown operation 'foo'"
+    Human,
+    /// a JSON object with stable error code, class, message, argument index, CLI flag, and operation
+    Json,
+}
+
+/// Print `err` on stderr in `format`. `op_name` is the operation requested (if any).
+fn report_error(err: &LibError, format: ErrorFormat, op_name: Option<&str>, color_scheme: opstr::ColorScheme) {
+    match format {
+        ErrorFormat::Json => eprintln!("{}", err.represent_json(op_name)),
+        ErrorFormat::Human => {
+            // NOTE: if the label cannot be written, the message is still worth a try
+            let mut stderr = color_scheme.stderr();
+            let _ = color_scheme.error_label(&mut stderr, "ERROR");
+            let _ = writeln!(stderr, ": {}", err);
+            if let LibError::UnknownOp(name) = err {
+                if let Some(suggestion) = opstr::Registry::default().suggest_op_name(name) {
+                    let _ = writeln!(stderr, "Did you mean ‘{}’?", suggestion);
+                }
+            }
+        },
+    }
+}
+
+fn main() {
     let opts = Opts::parse();
+    let error_format = opts.error_format;
+    let op_name = opts.op.clone();
+
+    // the color scheme is only known once the configuration has been read
+    let mut color_scheme = opstr::ColorScheme::default();
+    if let Err(err) = run(opts, &mut color_scheme) {
+        report_error(&err, error_format, op_name.as_deref(), color_scheme);
+        process::exit(err.class().exit_code());
+    }
+}
+
+fn run(opts: Opts, color_scheme: &mut opstr::ColorScheme) -> Result<(), LibError> {
+    // (1) Prepare configuration
+    if let Some(shell) = opts.completions {
+        print_completions(shell);
+        return Ok(());
+    }
+
+    // an argument streamed into the operation is not read into memory in advance
+    let stream_source = streaming_source(&opts);
+    let to_read = if stream_source.is_some() { &opts.args[..0] } else { &opts.args[..] };
     let mut arguments = vec![];
 
-    'outer: for (i, arg) in opts.args.iter().enumerate() {
+    'outer: for (i, arg) in to_read.iter().enumerate() {
         // handle stdin arguments
         for stdin_i in opts.stdin_as_arg.iter() {
             if *stdin_i == i + 1 {
@@ -106,7 +265,43 @@ fn main() -> Result<(), LibError> {
                 continue 'outer;
             }
         }
-        
+
+        // handle bytes arguments
+        for bytes_i in opts.bytes_as_arg.iter() {
+            if *bytes_i == i + 1 {
+                // read file or stdin content without decoding it
+                let mut buffer = Vec::new();
+                if arg == "-" {
+                    let stdin = std::io::stdin();
+                    let mut handle = stdin.lock();
+                    handle.read_to_end(&mut buffer)?;
+                } else {
+                    let filepath = path::Path::new(arg);
+                    if !filepath.exists() {
+                        return Err(LibError::CLIValueError("bytes-as-arg", format!("file '{}' not found", filepath.display())));
+                    }
+                    buffer = fs::read(filepath)?;
+                }
+
+                // add as argument
+                arguments.push(opstr::Arg::from_bytes(&buffer, i));
+                continue 'outer;
+            }
+        }
+
+        // handle hexadecimal arguments
+        for hex_i in opts.hex_as_arg.iter() {
+            if *hex_i == i + 1 {
+                let hex = match arg.to_str() {
+                    Some(s) => s,
+                    None => return Err(LibError::CLIValueError("hex-as-arg", "expected hexadecimal digits, got non-UTF-8 bytes".to_owned())),
+                };
+
+                arguments.push(opstr::Arg::from_hex(hex, i)?);
+                continue 'outer;
+            }
+        }
+
         if let Some(utf8_string) = arg.to_str() {
             arguments.push(opstr::Arg::from_str(utf8_string, i));
         } else {
@@ -118,32 +313,100 @@ fn main() -> Result<(), LibError> {
 
     let mut conf = Configuration::default();
     conf.overwrite_with_env()?;
-    conf.overwrite_with_clap(opts.radix, opts.item, opts.column, opts.hex_upper, opts.color_scheme, opts.locale, opts.syntax)?;
+    conf.overwrite_with_files(opts.config.as_deref())?;
+    conf.overwrite_with_clap(opts.radix, opts.item, opts.column, opts.hex_upper, opts.color_scheme, opts.locale, opts.syntax, opts.association_order)?;
+    conf.overwrite_ranking_filters(opts.min_priority, opts.top)?;
+    conf.overwrite_timeout(opts.timeout)?;
+    *color_scheme = conf.color_scheme.clone();
 
     if opts.dump {
         println!("{:?}", args);
-        println!("{:?}", conf);
-        return Ok(());
+        return conf.dump().print(&conf);
 
     } else if opts.list_color_scheme {
         return Ok(opstr::list_color_schemes(&conf)?.print(&conf)?);
     }
 
+    if opts.repl {
+        // start interactive session with the initial arguments
+        return opstr::repl_stdin(&opstr::Registry::default(), conf, args);
+    }
+
+    if opts.serve {
+        // answer requests until stdin is closed
+        let stdin = std::io::stdin();
+        let stdout = std::io::stdout();
+        return opstr::serve(&opstr::Registry::default(), &conf, stdin.lock(), stdout.lock());
+    }
+
+    #[cfg(feature = "http")]
+    if let Some(address) = opts.http {
+        return opstr::serve_http(&opstr::Registry::default(), &conf, &address);
+    }
+
+    if let Some(op_name) = opts.help_op {
+        // describe the operation and show its examples with their expected output
+        let registry = opstr::Registry::default();
+        registry.help_op(&conf, &op_name)?.print(&conf)?;
+        if let Some(op) = registry.get(&op_name) {
+            for example in op.examples() {
+                println!();
+                conf.color_scheme.keyword(&mut conf.color_scheme.stdout(), &format!("$ {}", example.command_line(op.name())))?;
+                println!();
+                example.output.print(&conf)?;
+            }
+        }
+        return Ok(());
+    }
+
     if opts.list_ops {
-        // list all operations
-        opstr::list_all_ops(&conf).print(&conf)?
+        // list all operations matching the keywords provided as arguments
+        let keywords = args.iter().filter_map(|arg| arg.try_into().ok()).collect::<Vec<&str>>();
+        opstr::search_ops(&conf, &keywords, opts.details).print(&conf)?
         
+    } else if opts.explain_ranking && opts.op.is_some() {
+        return Err(LibError::CLIValueError("explain-ranking", "the ranking only applies if no operation is specified with '--op'".to_owned()));
+
     } else if let Some(op_name) = opts.op {
-        // apply the mentioned operation
-        match opstr::run_op(&conf, &args, &op_name) {
-            Ok((_fn_name, fn_output)) => fn_output.print(&conf)?,
-            Err(err) => {
-                conf.color_scheme.error_label("ERROR")?;
-                eprintln!(": {}", err);
-                process::exit(2);
+        // apply the mentioned operation and all operations of the pipeline
+        let mut op_names = vec![op_name.as_str()];
+        op_names.extend(opts.then.iter().map(|s| s.as_str()));
+
+        let result = match opts.per_line_as_arg {
+            Some(0) => return Err(LibError::CLIValueError("per-line-as-arg", "argument IDs are one-based".to_owned())),
+            Some(line_arg) => opstr::run_ops_per_line(&conf, &args, &op_names, line_arg - 1),
+            None => match &stream_source {
+                Some(source) => run_streaming(&conf, source, &op_name),
+                None => opstr::run_ops_pipeline(&conf, &args, &op_names),
             },
         };
 
+        let (fn_name, fn_output) = result?;
+        if opts.exit_status {
+            if opts.print_output {
+                fn_output.print(&conf)?;
+            }
+            match fn_output.truth_value() {
+                Some(true) => process::exit(0),
+                Some(false) => process::exit(1),
+                None => return Err(LibError::CLIValueError("exit-status", format!("operation '{}' did not return a boolean, but --exit-status requires one", fn_name))),
+            }
+        }
+        fn_output.print(&conf)?;
+
+    } else if opts.exit_status {
+        return Err(LibError::CLIValueError("exit-status", "an exit status requires an operation specified with '--op'".to_owned()));
+
+    } else if opts.per_line_as_arg.is_some() {
+        return Err(LibError::CLIValueError("per-line-as-arg", "running per line requires an operation specified with '--op'".to_owned()));
+
+    } else if !opts.then.is_empty() {
+        return Err(LibError::CLIValueError("then", "a pipeline requires its first operation specified with '--op'".to_owned()));
+
+    } else if opts.explain_ranking {
+        // explain which operations would be run instead of running them
+        opstr::explain_ranking(&conf, &args).print(&conf)?;
+
     } else {
         opstr::run_matching_ops(&conf, &args)?;
     }
@@ -2,154 +2,618 @@
 //! given structure specification. If met, the corresponding operation is called
 //! to generate a result. All results are collected in `Results` and then represented
 //! when printing them to the CLI.
+//!
+//! If all matching operations are run, priorities and runs are evaluated concurrently
+//! on the thread pool of the `parallel` module and the results are buffered.
 
-use std::collections::HashMap;
+use std::io;
+use std::io::Write;
+use std::sync::Arc;
 
 use crate::auxiliary;
 use crate::errors::LibError;
 use crate::input;
 use crate::ops;
-use crate::config::Configuration;
-use crate::output::{Output,OutputValue};
-use crate::range;
+use crate::config::{Configuration, Syntax};
+use crate::output::{Output, OutputValue};
+use crate::parallel::{self, Job, JobResult};
+use crate::params;
+use crate::registry::{Op, Registry};
 use crate::ColorScheme;
+use crate::theme::Theme;
 
-/// Return the list of all operations as `Output::Association` of (name, description) entries.
-pub fn list_all_ops(_conf: &Configuration) -> Output {
-    let mut results = HashMap::new();
+use serde_json::json;
 
-    for (fn_name, fn_desc, _, _, _, _) in ops::INDEX {
-        results.insert(OutputValue::from_str(fn_name()), OutputValue::from_str(fn_desc()));
-    }
+/// Return the list of all built-in operations as `Output::Association` of (name, description) entries.
+pub fn list_all_ops(conf: &Configuration) -> Output {
+    Registry::default().list_all_ops(conf)
+}
 
-    Output::Association { data: results, notes: vec![] }
+/// Return the built-in operations matching all `keywords` (or all operations if none are given) as `Output::Table`.
+/// See `Registry::search_ops` for details.
+pub fn search_ops(conf: &Configuration, keywords: &[&str], details: bool) -> Output {
+    Registry::default().search_ops(conf, keywords, details)
 }
 
-/// Return the ordered list of appropriate operations as `Output::Association` of (name, description) entries.
+/// Return the description, usage and properties of the built-in operation `op_name`.
+/// See `Registry::help_op` for details.
+pub fn help_op(conf: &Configuration, op_name: &str) -> Result<Output, LibError> {
+    Registry::default().help_op(conf, op_name)
+}
+
+/// Return the ordered list of appropriate built-in operations as (name, description) entries.
 pub fn list_matching_ops(conf: &Configuration, args: &input::Args) -> Vec<(&'static str, &'static str)> {
-    let mut fns = vec![];
-
-    for (fn_name, fn_desc, _fn_usage, fn_args, fn_priority, _fn_impl) in ops::INDEX {
-        // Only consider functions where `acceptable_number_of_arguments` returns a range
-        // where the given number of arguments is within
-        if !fn_args().has(args.len()) {
-            continue;
-        }
-        // CONSTRAINT: priority must be greater 0
-        if let Ok(prio) = fn_priority(args, conf) {
-            if prio > 0.0 && !prio.is_nan() {
-                fns.push((fn_name(), fn_desc(), prio));
+    Registry::default().list_matching_ops(conf, args)
+}
+
+/// Explain which built-in operations `run_matching_ops` runs for the provided arguments.
+/// See `Registry::explain_ranking` for details.
+pub fn explain_ranking(conf: &Configuration, args: &input::Args) -> Output {
+    Registry::default().explain_ranking(conf, args)
+}
+
+/// Return `(op_name, Output)` as result of running the specified built-in operation `op_name` with `Args`
+pub fn run_op(conf: &Configuration, args: &input::Args, op_name: &str) -> Result<(&'static str, Output), LibError> {
+    Registry::default().run_op(conf, args, op_name)
+}
+
+/// Return `(op_name, Output)` as result of running the built-in operation `op_name` with a single argument read from `input`.
+/// See `Registry::run_op_streaming` for details.
+pub fn run_op_streaming(conf: &Configuration, input: &mut dyn io::Read, op_name: &str) -> Result<(&'static str, Output), LibError> {
+    Registry::default().run_op_streaming(conf, input, op_name)
+}
+
+/// Return `(op_name, Output)` as result of running the built-in operations `op_names` in the given order.
+/// The first operation receives `Args`, every subsequent operation receives the `Output` of its predecessor as `Args`.
+pub fn run_ops_pipeline(conf: &Configuration, args: &input::Args, op_names: &[&str]) -> Result<(&'static str, Output), LibError> {
+    Registry::default().run_ops_pipeline(conf, args, op_names)
+}
+
+/// Return `(op_name, Output)` as result of running the built-in operations `op_names` once for every line of
+/// the (zero-based) `line_arg`-th argument. The argument is replaced by the line in every run.
+/// The individual outputs are merged with `Output::merge_lines`.
+pub fn run_ops_per_line(conf: &Configuration, args: &input::Args, op_names: &[&str], line_arg: usize) -> Result<(&'static str, Output), LibError> {
+    Registry::default().run_ops_per_line(conf, args, op_names, line_arg)
+}
+
+/// Run all built-in operations appropriate for the provided `Args`. Write the result to stdout & stderr, so we return `()`
+pub fn run_matching_ops(conf: &Configuration, args: &input::Args) -> Result<(), LibError> {
+    Registry::default().run_matching_ops(conf, args)
+}
+
+/// An operation with whether it accepts the number of arguments and its priority (or why it has none)
+type RankedOp<'r> = (&'r Arc<dyn Op>, bool, Result<f32, LibError>);
+
+impl Registry {
+    /// Return the list of all registered operations as `Output::Association` of (name, description) entries.
+    pub fn list_all_ops(&self, _conf: &Configuration) -> Output {
+        let mut results = vec![];
+
+        for op in self.iter() {
+            results.push((OutputValue::from_str(op.name()), OutputValue::from_str(op.description())));
+        }
+
+        Output::Association { data: results, notes: vec![] }
+    }
+
+    /// Return the registered operations matching all `keywords` (or all operations if none are given) as `Output::Table`
+    /// with columns name and description. If `details` is set, the columns usage and number of arguments are added.
+    /// Keywords are compared case-insensitively with the op name, description and usage string.
+    /// Rows are sorted by decreasing relevance, then by name.
+    pub fn search_ops(&self, _conf: &Configuration, keywords: &[&str], details: bool) -> Output {
+        let keywords = keywords.iter().map(|k| k.to_lowercase()).collect::<Vec<String>>();
+        let mut matches = vec![];
+
+        for op in self.iter() {
+            let mut score = 1.0;
+            for keyword in keywords.iter() {
+                score *= keyword_score(keyword, op.name(), op.description(), op.usage());
+            }
+            if score > 0.0 {
+                matches.push((op, score));
             }
         }
+
+        matches.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.name().cmp(b.0.name())));
+
+        let mut column_headers = vec!["name".to_owned(), "description".to_owned()];
+        if details {
+            column_headers.extend(["usage".to_owned(), "number of arguments".to_owned()]);
+        }
+
+        let data = matches.iter().map(|(op, _)| {
+            let mut row = vec![OutputValue::from_str(op.name()), OutputValue::from_str(op.description())];
+            if details {
+                row.push(OutputValue::from_str(op.usage()));
+                row.push(OutputValue::from_str(&op.acceptable_number_of_arguments().to_string()));
+            }
+            row
+        }).collect::<Vec<Vec<OutputValue>>>();
+
+        let notes = if data.is_empty() { vec!["no operation matches the provided keywords".to_owned()] } else { vec![] };
+        Output::Table { data, column_headers, notes }
     }
 
-    // sort by priority
-    fns.sort_by_key(|e| (-1000.0 * e.2) as i32);
+    /// Return the description, usage, parameters, accepted number of arguments, required cargo feature,
+    /// and locale sensitivity of operation `op_name` as `Output::Association`. Its examples are provided by `Op::examples`.
+    pub fn help_op(&self, _conf: &Configuration, op_name: &str) -> Result<Output, LibError> {
+        let op = match self.get(op_name) {
+            Some(op) => op,
+            None => return Err(LibError::UnknownOp(op_name.to_owned())),
+        };
 
-    // strip away third element
-    fns.iter().map(|e| { (e.0, e.1) }).collect::<Vec<(&'static str, &'static str)>>()
-}
+        let params = op.params().iter().map(|p| p.to_string()).collect::<Vec<String>>();
+        let data = vec![
+            (OutputValue::from_str("name"), OutputValue::from_str(op.name())),
+            (OutputValue::from_str("description"), OutputValue::from_str(op.description())),
+            (OutputValue::from_str("usage"), OutputValue::from_str(op.usage())),
+            (OutputValue::from_str("parameters"), OutputValue::from_str(&params.join("; "))),
+            (OutputValue::from_str("number of arguments"), OutputValue::from_str(&op.acceptable_number_of_arguments().to_string())),
+            (OutputValue::from_str("cargo feature"), OutputValue::from_str(op.feature().unwrap_or("none (always available)"))),
+            (OutputValue::from_str("locale-sensitive"), OutputValue::Bool(op.locale_sensitive())),
+            (OutputValue::from_str("examples"), OutputValue::Int(op.examples().len() as i64)),
+        ];
 
-/// Return `(op_name, Output)` as result of running the specified operation `op_name` with `Args`
-pub fn run_op(conf: &Configuration, args: &input::Args, op_name: &str) -> Result<(&'static str, Output), LibError> {
-    // (1) search for an args-independent exact match
-    if let Some((name, usage, acceptable_range)) = find_op_by_exact_name(conf, args, op_name) {
-        if !acceptable_range.has(args.len()) {
-            return Err(LibError::ArgumentCountError(acceptable_range, args.len(), Some(usage.to_owned())));
+        Ok(Output::Association { data, notes: vec![] })
+    }
+
+    /// Determine for every registered operation whether it accepts `args` and its priority (or why it has none).
+    /// The boolean indicates whether the number of arguments is acceptable.
+    /// Priorities are computed concurrently, each within `Configuration.op_timeout`.
+    fn rank_ops(&self, conf: &Configuration, args: &input::Args) -> Vec<RankedOp<'_>> {
+        let mut ranking = vec![];
+        let mut jobs: Vec<Job<Result<f32, LibError>>> = vec![];
+        let shared_args = Arc::new(args.clone());
+        let shared_conf = Arc::new(conf.clone());
+
+        for op in self.shared() {
+            // Only consider functions where `acceptable_number_of_arguments` returns a range
+            // where the given number of arguments is within
+            let acceptable_range = op.acceptable_number_of_arguments();
+            if !acceptable_range.has(args.len()) {
+                ranking.push((op, false, Err(LibError::ArgumentCountError(acceptable_range, args.len(), None))));
+                continue;
+            }
+            // CONSTRAINT: arguments must satisfy the parameter types
+            if let Err(e) = params::validate(&op.params(), args) {
+                ranking.push((op, true, Err(e)));
+                continue;
+            }
+
+            // the priority is filled in once the job finished
+            ranking.push((op, true, Ok(0.0)));
+            let (op, args, conf) = (Arc::clone(op), Arc::clone(&shared_args), Arc::clone(&shared_conf));
+            jobs.push(Box::new(move || op.priority(&args, &conf)));
+        }
+
+        let mut priorities = parallel::run_jobs(jobs, conf.op_timeout).into_iter();
+        for (op, _, prio) in ranking.iter_mut().filter(|(_, _, prio)| prio.is_ok()) {
+            if let Some(result) = priorities.next() {
+                *prio = job_outcome(op.as_ref(), result, conf);
+            }
         }
-        return Ok((name, run_op_by_name(conf, args, op_name)?));
+
+        ranking
     }
 
-    // (2) Collect operation names and string_similarity
-    let mut names_and_similarity = vec![];
-    for (fn_name, _, _, _, _, _) in ops::INDEX {
-        names_and_similarity.push((fn_name(), auxiliary::string_similarity(fn_name(), op_name)));
+    /// Return the registered operations appropriate for `args` with their priority ordered by decreasing priority.
+    /// Only operations with priority greater 0 and at least `Configuration.min_priority` are selected.
+    /// If `Configuration.top` is set, at most that many operations are returned.
+    fn select_ops(&self, conf: &Configuration, args: &input::Args) -> Vec<(&Arc<dyn Op>, f32)> {
+        Self::select_from_ranking(conf, &self.rank_ops(conf, args))
     }
 
-    // (3) Sort ops by similarity
-    names_and_similarity.sort_by_key(|e| (1000.0 * e.1) as i32);
+    /// Select the operations from `ranking` (as returned by `rank_ops`) according to `select_ops`
+    fn select_from_ranking<'r>(conf: &Configuration, ranking: &[RankedOp<'r>]) -> Vec<(&'r Arc<dyn Op>, f32)> {
+        let mut selected = ranking.iter()
+            .filter_map(|(op, _, prio)| match prio {
+                Ok(p) if *p > 0.0 && !p.is_nan() && *p >= conf.min_priority => Some((*op, *p)),
+                _ => None,
+            })
+            .collect::<Vec<(&Arc<dyn Op>, f32)>>();
+
+        // sort by priority
+        selected.sort_by(|a, b| b.1.total_cmp(&a.1));
 
-    // (4) Yield message "Did you mean …?"
-    if let Some((last, _)) = names_and_similarity.last() {
-        eprintln!("Did you mean ‘{}’?", last);
+        if let Some(top) = conf.top {
+            selected.truncate(top);
+        }
+        selected
     }
 
-    Err(LibError::UnknownOp(op_name.to_owned()))
-}
+    /// Return the ordered list of appropriate registered operations as (name, description) entries.
+    pub fn list_matching_ops(&self, conf: &Configuration, args: &input::Args) -> Vec<(&'static str, &'static str)> {
+        self.select_ops(conf, args).iter().map(|(op, _)| (op.name(), op.description())).collect()
+    }
+
+    /// Explain which registered operations `run_matching_ops` runs for `args` in which order as `Output::Table`.
+    /// Every operation is listed with its accepted number of arguments, whether `args` matches it,
+    /// its priority, and its status (selected or the reason why it was dropped).
+    /// Selected operations come first ordered by decreasing priority. The filters `Configuration.min_priority`
+    /// and `Configuration.top` apply to the rows as well.
+    pub fn explain_ranking(&self, conf: &Configuration, args: &input::Args) -> Output {
+        let mut ranking = self.rank_ops(conf, args);
+        let selected = Self::select_from_ranking(conf, &ranking).iter().map(|(op, _)| op.name()).collect::<Vec<&'static str>>();
+
+        // selected operations in their order first, then all others ordered by decreasing priority
+        let rank_of = |name: &str| selected.iter().position(|n| *n == name).unwrap_or(selected.len());
+        ranking.sort_by(|a, b| {
+            let prio = |r: &Result<f32, LibError>| *r.as_ref().unwrap_or(&-1.0);
+            rank_of(a.0.name()).cmp(&rank_of(b.0.name())).then_with(|| prio(&b.2).total_cmp(&prio(&a.2)))
+        });
+
+        let mut data = vec![];
+        for (op, range_match, prio) in ranking.iter() {
+            let priority = match prio {
+                Ok(p) => format!("{:.3}", p),
+                Err(_) => String::new(),
+            };
+            let status = match prio {
+                _ if selected.contains(&op.name()) => "selected".to_owned(),
+                Err(LibError::ArgumentCountError(..)) => "dropped: number of arguments not accepted".to_owned(),
+                Err(e) => format!("dropped: {}", e),
+                Ok(p) if *p <= 0.0 || p.is_nan() => "dropped: priority is zero".to_owned(),
+                Ok(p) if *p < conf.min_priority => "dropped: below minimum priority".to_owned(),
+                Ok(_) => "dropped: not among top operations".to_owned(),
+            };
+
+            // apply the filters to the explanation too
+            if (conf.min_priority > 0.0 || conf.top.is_some()) && !selected.contains(&op.name()) {
+                continue;
+            }
+
+            data.push(vec![
+                OutputValue::from_str(op.name()),
+                OutputValue::from_str(&op.acceptable_number_of_arguments().to_string()),
+                OutputValue::Bool(*range_match),
+                OutputValue::from_str(&priority),
+                OutputValue::from_str(&status),
+            ]);
+        }
 
-fn find_op_by_exact_name(_conf: &Configuration, _args: &input::Args, op_name: &str) -> Option<(&'static str, &'static str, range::Range)> {
-    for (fn_name, _fn_desc, fn_usage, fn_args, _fn_priority, _fn_impl) in ops::INDEX {
-        // CONSTRAINT: name must match user-provided name
-        if fn_name() != op_name {
-            continue;
+        Output::Table {
+            data,
+            column_headers: ["name", "number of arguments", "range match", "priority", "status"].iter().map(|h| h.to_string()).collect(),
+            notes: vec![],
         }
-        // run the function
-        return Some((fn_name(), fn_usage(), fn_args()));
     }
 
-    None
-}
+    /// Return `(op_name, Output)` as result of running the specified operation `op_name` with `Args`.
+    /// Arguments are validated against the parameters of the operation before it is run.
+    /// Missing arguments are completed with the default arguments of `Configuration.op_defaults`.
+    pub fn run_op(&self, conf: &Configuration, args: &input::Args, op_name: &str) -> Result<(&'static str, Output), LibError> {
+        // (1) search for an args-independent exact match
+        if let Some(op) = self.get(op_name) {
+            let args = &conf.args_with_op_defaults(op.name(), args);
+            let acceptable_range = op.acceptable_number_of_arguments();
+            if !acceptable_range.has(args.len()) {
+                return Err(LibError::ArgumentCountError(acceptable_range, args.len(), Some(op.usage().to_owned())));
+            }
+            params::validate(&op.params(), args)?;
+            return Ok((op.name(), op.run(args, conf)?));
+        }
+
+        Err(LibError::UnknownOp(op_name.to_owned()))
+    }
+
+    /// Return the name of the registered operation most similar to the unknown `op_name`,
+    /// which can be suggested to the user after `run_op` failed with `LibError::UnknownOp`
+    pub fn suggest_op_name(&self, op_name: &str) -> Option<&'static str> {
+        // (1) Collect operation names and string_similarity
+        let mut names_and_similarity = vec![];
+        for op in self.iter() {
+            names_and_similarity.push((op.name(), auxiliary::string_similarity(op.name(), op_name)));
+        }
 
-fn run_op_by_name(conf: &Configuration, args: &input::Args, op_name: &str) -> Result<Output, LibError> {
-    for (fn_name, _fn_desc, _fn_usage, _fn_args, _fn_priority, fn_impl) in ops::INDEX {
-        if fn_name() != op_name {
-            continue;
+        // (2) Sort ops by similarity
+        names_and_similarity.sort_by_key(|e| (1000.0 * e.1) as i32);
+
+        names_and_similarity.last().map(|(name, _)| *name)
+    }
+
+    /// Return `(op_name, Output)` as result of running operation `op_name` with a single argument read from `input`
+    /// chunk by chunk. Thus the argument is never held in memory as a whole. Fails if the operation does not support
+    /// streaming (see `Op::streaming`) or does not accept a single argument.
+    pub fn run_op_streaming(&self, conf: &Configuration, input: &mut dyn io::Read, op_name: &str) -> Result<(&'static str, Output), LibError> {
+        let op = match self.get(op_name) {
+            Some(op) => op,
+            None => return Err(LibError::UnknownOp(op_name.to_owned())),
+        };
+
+        let acceptable_range = op.acceptable_number_of_arguments();
+        if !acceptable_range.has(1) {
+            return Err(LibError::ArgumentCountError(acceptable_range, 1, Some(op.usage().to_owned())));
+        }
+        if !op.streaming() {
+            return Err(LibError::CLIValueError("op", format!("operation '{}' cannot read its argument as stream", op.name())));
         }
-        return fn_impl(args, conf);
+
+        Ok((op.name(), op.run_stream(input, conf)?))
     }
 
-    Err(LibError::UnknownOp(op_name.to_owned()))
-}
+    /// Return `(op_name, Output)` as result of running the operations `op_names` in the given order.
+    /// The first operation receives `Args`, every subsequent operation receives the `Output` of its predecessor as `Args`.
+    pub fn run_ops_pipeline(&self, conf: &Configuration, args: &input::Args, op_names: &[&str]) -> Result<(&'static str, Output), LibError> {
+        let (first_op, subsequent_ops) = match op_names.split_first() {
+            Some(split) => split,
+            None => return Err(LibError::CLIValueError("op", "no operation specified for the pipeline".to_owned())),
+        };
 
-/// Run all operations appropriate for the provided `Args`. Write the result to stdout & stderr, so we return `()`
-pub fn run_matching_ops(conf: &Configuration, args: &input::Args) -> Result<(), LibError> {
-    // (1) determine the set of functions accepting this number of arguments and returning priority > 0.0
-    let mut priority_per_function: Vec<(&'static str, f32)> = vec![];
-
-    // NOTE: INDEX_MULTI must always be run, independent of the number of arguments
-    for (fn_name, _fn_desc, _fn_usage, fn_args, fn_priority, _fn_impl) in ops::INDEX {
-        let name: &'static str = fn_name();
-        if !fn_args().has(args.len()) {
-            continue;
-        }
-        if let Ok(prio) = fn_priority(args, conf) {
-            if prio > 0.0 && !prio.is_nan() {
-                priority_per_function.push((name, prio));
+        let (mut prev_name, mut prev_output) = self.run_op(conf, args, first_op)?;
+
+        for (i, op_name) in subsequent_ops.iter().enumerate() {
+            let stage = i + 1;
+
+            // (1) convert previous output into arguments
+            let stage_args = match input::Args::try_from(&prev_output) {
+                Ok(a) => a,
+                Err(e) => return Err(LibError::PipelineError(stage, format!("output of '{}' cannot be passed on to '{}': {}", prev_name, op_name, e))),
+            };
+
+            // (2) verify that the number of values (completed by default arguments) is acceptable for the next op
+            if let Some(op) = self.get(op_name) {
+                let acceptable_range = op.acceptable_number_of_arguments();
+                if !acceptable_range.has(conf.args_with_op_defaults(op.name(), &stage_args).len()) {
+                    return Err(LibError::PipelineError(stage, format!(
+                        "'{}' returned {} value(s), but '{}' expects {} argument(s): {}",
+                        prev_name, stage_args.len(), op.name(), acceptable_range, op.usage()
+                    )));
+                }
+            }
+
+            // (3) run the op and keep the notes of previous stages
+            let (name, mut output) = self.run_op(conf, &stage_args, op_name)?;
+            for note in prev_output.notes() {
+                output.add_note(&format!("{}: {}", prev_name, note));
+            }
+
+            prev_name = name;
+            prev_output = output;
+        }
+
+        Ok((prev_name, prev_output))
+    }
+
+    /// Return `(op_name, Output)` as result of running the operations `op_names` once for every line of
+    /// the (zero-based) `line_arg`-th argument. The argument is replaced by the line in every run.
+    /// The individual outputs are merged with `Output::merge_lines`.
+    pub fn run_ops_per_line(&self, conf: &Configuration, args: &input::Args, op_names: &[&str], line_arg: usize) -> Result<(&'static str, Output), LibError> {
+        if line_arg >= args.len() {
+            return Err(LibError::CLIValueError("per-line-as-arg", format!("argument #{} does not exist, only {} arguments provided", line_arg + 1, args.len())));
+        }
+
+        let content: &str = args.get(line_arg)?.try_into()?;
+        let name = match op_names.last().and_then(|op_name| self.get(op_name)) {
+            Some(op) => op.name(),
+            None => return Err(LibError::UnknownOp(op_names.last().copied().unwrap_or_default().to_owned())),
+        };
+        let mut outputs = vec![];
+        let mut line_args = args.clone();
+
+        for (i, line) in content.lines().enumerate() {
+            line_args.replace(line_arg, input::Arg::from_str(line, line_arg))?;
+
+            let (_, output) = match self.run_ops_pipeline(conf, &line_args, op_names) {
+                Ok(result) => result,
+                Err(e) => return Err(LibError::ArgValueError(line_arg, format!("line {}: {}", i + 1, e))),
+            };
+
+            outputs.push((i + 1, output));
+        }
+
+        Ok((name, Output::merge_lines(&outputs)?))
+    }
+
+    /// Run all registered operations appropriate for the provided `Args` and return `(op_name, priority, result)`
+    /// ordered by decreasing priority. This is what `run_matching_ops` prints.
+    pub(crate) fn evaluate_matching_ops(&self, conf: &Configuration, args: &input::Args) -> Vec<(&'static str, f32, Result<Output, LibError>)> {
+        // (1) determine the set of functions accepting these arguments and returning priority > 0.0,
+        //     sorted by priority and restricted by the filters of `Configuration`
+        let priority_per_function = self.select_ops(conf, args);
+
+        // (2) invoke functions concurrently and buffer their results in priority order.
+        //     Operations rejecting the type of an argument (e.g. bytes instead of a string) are not considered matching.
+        let shared_args = Arc::new(args.clone());
+        let shared_conf = Arc::new(conf.clone());
+        let jobs = priority_per_function.iter().map(|(op, _)| {
+            let (op, args, conf) = (Arc::clone(op), Arc::clone(&shared_args), Arc::clone(&shared_conf));
+            Box::new(move || op.run(&args, &conf)) as Job<Result<Output, LibError>>
+        }).collect::<Vec<Job<Result<Output, LibError>>>>();
+
+        let mut results = vec![];
+        for ((op, priority), result) in priority_per_function.iter().zip(parallel::run_jobs(jobs, conf.op_timeout)) {
+            let result = job_outcome(op.as_ref(), result, conf);
+            if !matches!(result, Err(LibError::ArgTypeError(_, _))) {
+                results.push((op.name(), *priority, result));
             }
         }
+
+        results
     }
 
-    // (2) sort set by priorities
-    priority_per_function.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap().reverse());
+    /// Run all registered operations appropriate for the provided `Args`. Write the result to stdout & stderr, so we return `()`
+    pub fn run_matching_ops(&self, conf: &Configuration, args: &input::Args) -> Result<(), LibError> {
+        // (1) run the operations
+        let results = self.evaluate_matching_ops(conf, args);
 
-    // (3) invoke functions in correct order
-    for (op_name, _) in priority_per_function {
-        conf.color_scheme.op_section(op_name)?;
+        // (2) print results
+        let is_json = matches!(conf.syntax, Syntax::Json | Syntax::JsonLines);
+        if conf.syntax == Syntax::Json {
+            println!("[");
+        }
 
-        for (fn_name, _fn_desc, _fn_usage, _fn_args, _fn_priority, fn_impl) in ops::INDEX {
-            let name: &'static str = fn_name();
-            if name != op_name {
+        for (i, (op_name, priority, result)) in results.iter().enumerate() {
+            if is_json {
+                // one JSON object per op; in the JSON syntax, objects are separated by commas to form an array
+                let separator = if conf.syntax == Syntax::Json && i != results.len() - 1 { "," } else { "" };
+                println!("{}{}", json_op_result(conf, op_name, *priority, result), separator);
                 continue;
             }
 
-            match fn_impl(args, conf) {
+            conf.color_scheme.op_section(&mut conf.color_scheme.stdout(), op_name)?;
+            match result {
                 Ok(output) => { output.print(conf)?; },
                 Err(e) => {
-                    conf.color_scheme.error_label("ERROR")?;
-                    eprintln!(": {}", e);
+                    let mut stderr = conf.color_scheme.stderr();
+                    conf.color_scheme.error_label(&mut stderr, "ERROR")?;
+                    writeln!(stderr, ": {}", e)?;
                 },
             }
         }
+
+        if conf.syntax == Syntax::Json {
+            println!("]");
+        }
+
+        Ok(())
     }
+}
 
-    Ok(())
+/// Return a number between 0 (unrelated) and 1 (equal name) how well lowercase `keyword` describes an op
+fn keyword_score(keyword: &str, name: &str, description: &str, usage: &str) -> f32 {
+    if name == keyword {
+        return 1.0;
+    }
+    if name.contains(keyword) {
+        return 0.9;
+    }
+    let description = description.to_lowercase();
+    if description.contains(keyword) {
+        return 0.7;
+    }
+    if usage.to_lowercase().contains(keyword) {
+        return 0.5;
+    }
+
+    // tolerate typos by comparing with the words of name and description (name words weigh more)
+    let similarity = |word: &str| {
+        let distance = ops::levenshtein_d1stance(&word, &keyword);
+        1.0 - distance as f32 / usize::max(word.chars().count(), keyword.chars().count()) as f32
+    };
+    let best_similarity = name.split('-').map(|word| 1.1 * similarity(word))
+        .chain(description.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).map(similarity))
+        .fold(0.0, f32::max);
+
+    if best_similarity >= 0.8 { 0.4 * best_similarity.min(1.0) } else { 0.0 }
 }
 
-/// Run all operations appropriate for the provided `Args`. Write the result to stdout & stderr, so we return `()`
+/// Turn the outcome of a job evaluating `op` into the result of the operation
+fn job_outcome<T>(op: &dyn Op, outcome: JobResult<Result<T, LibError>>, conf: &Configuration) -> Result<T, LibError> {
+    match outcome {
+        JobResult::Done(result) => result,
+        JobResult::TimedOut => Err(LibError::OpAborted(op.name().to_owned(), "timeout", format!("did not finish within {:?}", conf.op_timeout))),
+        JobResult::Panicked => Err(LibError::OpAborted(op.name().to_owned(), "panic", "it panicked".to_owned())),
+        JobResult::Refused => Err(LibError::OpAborted(op.name().to_owned(), "refused", "too many aborted operations are still running".to_owned())),
+    }
+}
+
+/// Return the result of running op `op_name` with priority `priority` as JSON object
+pub(crate) fn json_op_result(conf: &Configuration, op_name: &str, priority: f32, result: &Result<Output, LibError>) -> serde_json::Value {
+    // NOTE: the decimal representation of f32 is shorter than the one of the corresponding f64
+    let priority = priority.to_string().parse::<f64>().unwrap_or_default();
+    match result {
+        Ok(output) => json!({"op": op_name, "priority": priority, "result": output.reduce(conf).to_json(conf)}),
+        Err(e) => {
+            let mut members = serde_json::Map::new();
+            members.insert("op".to_owned(), json!(op_name));
+            members.insert("priority".to_owned(), json!(priority));
+            members.extend(e.to_json(Some(op_name)));
+            serde_json::Value::Object(members)
+        },
+    }
+}
+
+/// Return the names of the built-in color schemes followed by the names of the user-defined themes
 pub fn list_color_schemes(_conf: &Configuration) -> Result<Output, LibError> {
     let mut cs_list = vec![];
     for cs in ColorScheme::all_as_slice() {
         cs_list.push(OutputValue::from_str(&cs.to_string()));
     }
+    for name in Theme::available() {
+        cs_list.push(OutputValue::from_str(&name));
+    }
     Ok(Output::HomogeneousList { data: cs_list, notes: vec![] })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_ops() {
+        let conf = Configuration::default();
+        let names = |output: Output| match output {
+            Output::Table { data, .. } => data.iter().map(|row| match &row[0] {
+                OutputValue::SingleLineText(name) => name.to_owned(),
+                _ => panic!("op name expected"),
+            }).collect::<Vec<String>>(),
+            _ => panic!("table expected"),
+        };
+
+        assert_eq!(names(search_ops(&conf, &["base64", "Decode"], false)), vec!["base64-decode", "base64-url-safe-decode"]);
+        assert_eq!(names(search_ops(&conf, &["upercase"], false))[0], "uppercase-for-ascii");
+        assert!(names(search_ops(&conf, &["xyzzy"], false)).is_empty());
+        assert_eq!(names(search_ops(&conf, &[], false)).len(), Registry::default().len());
+    }
+
+    #[test]
+    fn test_suggest_op_name() {
+        let registry = Registry::default();
+        assert!(matches!(registry.run_op(&Configuration::default(), &input::Args::from(&[]), "count-codepoint"), Err(LibError::UnknownOp(_))));
+        assert_eq!(registry.suggest_op_name("count-codepoint"), Some("count-codepoints"));
+        assert_eq!(Registry::empty().suggest_op_name("count-codepoint"), None);
+    }
+    #[test]
+    fn test_op_examples() {
+        let conf = Configuration::default();
+        let registry = Registry::default();
+
+        for op in registry.iter() {
+            // random output and bytes arguments cannot be expressed as example
+            if !["lorem-ipsum", "utf8-decode"].contains(&op.name()) {
+                assert!(!op.examples().is_empty(), "operation '{}' provides no examples", op.name());
+            }
+
+            for example in op.examples() {
+                let command_line = example.command_line(op.name());
+                let (_, output) = match registry.run_op(&conf, &example.to_args(), op.name()) {
+                    Ok(result) => result,
+                    Err(e) => panic!("example '{}' failed: {}", command_line, e),
+                };
+                assert!(example.matches(&output), "example '{}' returned {:?}", command_line, output);
+
+                // streaming the argument must give the same result
+                if op.streaming() && example.args.len() == 1 {
+                    let mut input = std::io::Cursor::new(example.args[0].as_bytes());
+                    let (_, streamed) = registry.run_op_streaming(&conf, &mut input, op.name()).unwrap();
+                    assert!(example.matches(&streamed), "streamed example '{}' returned {:?}", command_line, streamed);
+                }
+            }
+        }
+
+        assert!(help_op(&conf, "repeat").is_ok());
+        assert!(help_op(&conf, "no-such-op").is_err());
+    }
+    #[test]
+    fn test_explain_ranking() {
+        let mut conf = Configuration::default();
+        let args = input::Args::from(&[input::Arg::from_str("hello", 0), input::Arg::from_str("l", 1)]);
+        let rows = |output: Output| match output {
+            Output::Table { data, .. } => data,
+            _ => panic!("table expected"),
+        };
+
+        let all = rows(explain_ranking(&conf, &args));
+        assert_eq!(all.len(), Registry::default().len());
+        let selected = all.iter().filter(|row| row[4] == OutputValue::from_str("selected")).count();
+        assert_eq!(selected, list_matching_ops(&conf, &args).len());
+        assert_eq!(all[0][0], OutputValue::from_str(list_matching_ops(&conf, &args)[0].0));
+        assert!(all.iter().any(|row| row[0] == OutputValue::from_str("count-codepoints") && row[2] == OutputValue::Bool(false)));
+
+        conf.overwrite_ranking_filters(Some(0.1), Some(2)).unwrap();
+        assert_eq!(rows(explain_ranking(&conf, &args)).len(), 2);
+        assert_eq!(list_matching_ops(&conf, &args).len(), 2);
+        assert!(conf.overwrite_ranking_filters(Some(1.5), None).is_err());
+        assert!(conf.overwrite_ranking_filters(None, Some(0)).is_err());
+    }
+}
@@ -2,8 +2,9 @@ use base64::{Engine as _, engine::general_purpose as base64_engine};
 
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
@@ -19,13 +20,19 @@ impl Base64Decode {
     }
 }
 
-impl traits::Op for Base64Decode {
-    fn name() -> &'static str { "base64-decode" }
-    fn usage() -> &'static str { "<#1 string to-decode>" }
-    fn description() -> &'static str { "base64 decoding of provided hexadecimal string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Base64Decode {
+    fn name(&self) -> &'static str { "base64-decode" }
+    fn usage(&self) -> &'static str { "<#1 string to-decode>" }
+    fn description(&self) -> &'static str { "base64 decoding of provided hexadecimal string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["aGVsbG8"], Output::HomogeneousList { data: vec![OutputValue::Byte(104), OutputValue::Byte(101), OutputValue::Byte(108), OutputValue::Byte(108), OutputValue::Byte(111)], notes: vec![] }),
+            Example::new(&["b3BzdHI"], Output::HomogeneousList { data: vec![OutputValue::Byte(111), OutputValue::Byte(112), OutputValue::Byte(115), OutputValue::Byte(116), OutputValue::Byte(114)], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let base: &str = args.get(0)?.try_into()?;
         let length = base.chars().count();
 
@@ -39,7 +46,7 @@ impl traits::Op for Base64Decode {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let base: &str = args.get(0)?.try_into()?;
         match Self::function_for_chars(base) {
             Ok(decoded) => {
@@ -49,7 +56,7 @@ impl traits::Op for Base64Decode {
                 })
             },
             Err(_) => {
-                Err(LibError::ArgValueError(0, "provided argument is not a base64-encoded string".to_owned()))
+                Err(LibError::OpError(0, "invalid-base64", "provided argument is not a base64-encoded string".to_owned()))
             },
         }
     }
@@ -1,9 +1,13 @@
+use std::io::{self, Write};
+
 use crate::config::Configuration;
 use crate::errors::LibError;
-use crate::input::Args;
-use crate::ops::traits;
+use crate::example::Example;
+use crate::input::{Arg, Args};
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
+use crate::stream;
 
 use base64;
 use base64::{Engine as _, engine::general_purpose as base64_engine};
@@ -20,15 +24,23 @@ impl Base64Encode {
     }
 }
 
-impl traits::Op for Base64Encode {
-    fn name() -> &'static str { "base64-encode" }
-    fn usage() -> &'static str { "<#1 string to-encode>" }
-    fn description() -> &'static str { "base64 encoding of provided string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Base64Encode {
+    fn name(&self) -> &'static str { "base64-encode" }
+    fn usage(&self) -> &'static str { "<#1 string-or-bytes to-encode>" }
+    fn description(&self) -> &'static str { "base64 encoding of provided string or bytes #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], "aGVsbG8".into()),
+            Example::new(&["opstr"], "b3BzdHI".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
-        let base: &str = args.get(0)?.try_into()?;
-        let length = base.chars().count();
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+        let length = match args.get(0)? {
+            Arg::Chars(s, _) => s.chars().count(),
+            Arg::Bytes(b, _) => b.len(),
+        };
 
         Ok(if 12 <= length && length <= 256 {
             0.63
@@ -37,8 +49,18 @@ impl traits::Op for Base64Encode {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
-        let base: &str = args.get(0)?.try_into()?;
-        Ok(Self::function_for_chars(base).into())
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        Ok(match args.get(0)? {
+            Arg::Chars(s, _) => Self::function_for_chars(s),
+            Arg::Bytes(b, _) => Self::function_for_bitstring(b),
+        }.into())
+    }
+
+    fn streaming(&self) -> bool { true }
+
+    fn run_stream(&self, input: &mut dyn io::Read, _conf: &Configuration) -> Result<Output, LibError> {
+        let mut encoder = base64::write::EncoderStringWriter::new(&base64_engine::STANDARD_NO_PAD);
+        stream::for_each_chunk(input, |chunk| Ok(encoder.write_all(chunk)?))?;
+        Ok(encoder.into_inner().into())
     }
 }
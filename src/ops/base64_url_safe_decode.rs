@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
@@ -19,13 +20,19 @@ impl Base64UrlSafeDecode {
     }
 }
 
-impl traits::Op for Base64UrlSafeDecode {
-    fn name() -> &'static str { "base64-url-safe-decode" }
-    fn usage() -> &'static str { "<#1 string to-decode>" }
-    fn description() -> &'static str { "base64 decoding of provided string #1 with URL-appropriate representation (c.f. RFC 3548)" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Base64UrlSafeDecode {
+    fn name(&self) -> &'static str { "base64-url-safe-decode" }
+    fn usage(&self) -> &'static str { "<#1 string to-decode>" }
+    fn description(&self) -> &'static str { "base64 decoding of provided string #1 with URL-appropriate representation (c.f. RFC 3548)" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["Pz8_"], Output::HomogeneousList { data: vec![OutputValue::Byte(63), OutputValue::Byte(63), OutputValue::Byte(63)], notes: vec![] }),
+            Example::new(&["aGVsbG8"], Output::HomogeneousList { data: vec![OutputValue::Byte(104), OutputValue::Byte(101), OutputValue::Byte(108), OutputValue::Byte(108), OutputValue::Byte(111)], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let base: &str = args.get(0)?.try_into()?;
         let length = base.chars().count();
 
@@ -39,7 +46,7 @@ impl traits::Op for Base64UrlSafeDecode {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let base: &str = args.get(0)?.try_into()?;
         match Self::function_for_chars(base) {
             Ok(decoded) => {
@@ -49,7 +56,7 @@ impl traits::Op for Base64UrlSafeDecode {
                 })
             },
             Err(_) => {
-                Err(LibError::ArgValueError(0, "provided argument is not a base64-encoded string".to_owned()))
+                Err(LibError::OpError(0, "invalid-base64", "provided argument is not a base64-encoded string".to_owned()))
             },
         }
     }
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
-use crate::input::Args;
-use crate::ops::traits;
+use crate::example::Example;
+use crate::input::{Arg, Args};
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -21,15 +22,23 @@ impl Base64UrlSafeEncode {
     }
 }
 
-impl traits::Op for Base64UrlSafeEncode {
-    fn name() -> &'static str { "base64-url-safe-encode" }
-    fn usage() -> &'static str { "<#1 string to-encode>" }
-    fn description() -> &'static str { "base64 encoding of provided string #1 with URL-appropriate representation (c.f. RFC 3548)" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Base64UrlSafeEncode {
+    fn name(&self) -> &'static str { "base64-url-safe-encode" }
+    fn usage(&self) -> &'static str { "<#1 string-or-bytes to-encode>" }
+    fn description(&self) -> &'static str { "base64 encoding of provided string or bytes #1 with URL-appropriate representation (c.f. RFC 3548)" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["???"], "Pz8_".into()),
+            Example::new(&["hello"], "aGVsbG8".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
-        let base: &str = args.get(0)?.try_into()?;
-        let length = base.chars().count();
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+        let length = match args.get(0)? {
+            Arg::Chars(s, _) => s.chars().count(),
+            Arg::Bytes(b, _) => b.len(),
+        };
 
         Ok(if 12 <= length && length <= 256 {
             0.6
@@ -38,8 +47,10 @@ impl traits::Op for Base64UrlSafeEncode {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
-        let base: &str = args.get(0)?.try_into()?;
-        Ok(Self::function_for_chars(base).into())
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        Ok(match args.get(0)? {
+            Arg::Chars(s, _) => Self::function_for_chars(s),
+            Arg::Bytes(b, _) => Self::function_for_bitstring(b),
+        }.into())
     }
 }
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct Camelcase {}
 
-impl traits::Op for Camelcase {
-    fn name() -> &'static str { "camelcase" }
-    fn usage() -> &'static str { "<#1 string to-camelcase>" }
-    fn description() -> &'static str { "turn #1 to lowercase and replace the ASCII character after ' ' or '_' sequences with an uppercase letter" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Camelcase {
+    fn name(&self) -> &'static str { "camelcase" }
+    fn usage(&self) -> &'static str { "<#1 string to-camelcase>" }
+    fn description(&self) -> &'static str { "turn #1 to lowercase and replace the ASCII character after ' ' or '_' sequences with an uppercase letter" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello world"], "helloWorld".into()),
+            Example::new(&["snake_case_name"], "snakeCaseName".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s.matches(' ').count() > 0 || s.matches('_').count() > 0 {
             0.58
@@ -22,7 +29,7 @@ impl traits::Op for Camelcase {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let name: &str = args.get(0)?.try_into()?;
 
         // NOTE: this algorithm does NOT use the Unicode casing algorithms.
@@ -1,19 +1,30 @@
 use crate::config::Configuration;
 use crate::input::{Arg,Args};
 use crate::errors::LibError;
-use crate::ops::traits;
+use crate::example::Example;
+use crate::registry::Op;
 use crate::output::Output;
+use crate::params::Param;
 use crate::range;
 
 pub struct Center {}
 
-impl traits::Op for Center {
-    fn name() -> &'static str { "center" }
-    fn usage() -> &'static str { "<#1 string centered-text> [optional <#2 int width> [optional <#3 string repetition-char>]]" }
-    fn description() -> &'static str { "put string #1 in the middle of string of width #2 (default 80) repeating char #3 (default #) on both sides" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 3) }
+impl Op for Center {
+    fn name(&self) -> &'static str { "center" }
+    fn usage(&self) -> &'static str { "<#1 string centered-text> [optional <#2 int width> [optional <#3 codepoint repetition-char>]]" }
+    fn params(&self) -> Vec<Param> {
+        Param::with_defaults(Param::from_usage(self.usage()), &[("width", "80"), ("repetition-char", "#")])
+    }
+    fn description(&self) -> &'static str { "put string #1 in the middle of string of width #2 (default 80) repeating char #3 (default #) on both sides" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 3) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hi", "10"], "### hi ###".into()),
+            Example::new(&["hi", "9", "-"], "-- hi ---".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         let width: i64 = match args.get(1)?.try_into() {
             Ok(w) => w,
@@ -29,7 +40,7 @@ impl traits::Op for Center {
         Ok(score)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let default_w = Arg::Chars("80".to_owned(), 1);
         let default_rep = Arg::Chars("#".to_owned(), 2);
 
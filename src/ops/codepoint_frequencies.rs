@@ -1,8 +1,9 @@
 use crate::auxiliary;
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
@@ -10,13 +11,19 @@ use std::cmp::Ordering;
 
 pub struct CodepointFrequencies {}
 
-impl traits::Op for CodepointFrequencies {
-    fn name() -> &'static str { "codepoint-frequencies" }
-    fn usage() -> &'static str { "<#1 string to-analyze-statistically>" }
-    fn description() -> &'static str { "return the frequency analysis per codepoint of string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for CodepointFrequencies {
+    fn name(&self) -> &'static str { "codepoint-frequencies" }
+    fn usage(&self) -> &'static str { "<#1 string to-analyze-statistically>" }
+    fn description(&self) -> &'static str { "return the frequency analysis per codepoint of string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["aab"], Output::Table { data: vec![vec![OutputValue::Int(2), OutputValue::Int(66), OutputValue::from_str("a"), OutputValue::from_str("LATIN SMALL LETTER A")], vec![OutputValue::Int(1), OutputValue::Int(33), OutputValue::from_str("b"), OutputValue::from_str("LATIN SMALL LETTER B")]], column_headers: vec!["frequency".to_owned(), "percentage".to_owned(), "codepoint".to_owned(), "codepoint-name".to_owned()], notes: vec![] }),
+            Example::new(&["abba"], Output::Table { data: vec![vec![OutputValue::Int(2), OutputValue::Int(50), OutputValue::from_str("a"), OutputValue::from_str("LATIN SMALL LETTER A")], vec![OutputValue::Int(2), OutputValue::Int(50), OutputValue::from_str("b"), OutputValue::from_str("LATIN SMALL LETTER B")]], column_headers: vec!["frequency".to_owned(), "percentage".to_owned(), "codepoint".to_owned(), "codepoint-name".to_owned()], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         Ok(if 10 <= string.len() && string.len() <= 50 {
             0.67
@@ -25,7 +32,7 @@ impl traits::Op for CodepointFrequencies {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let codepoint_names = auxiliary::unicode_codepoint_names_lookup(&string.chars().collect::<Vec<char>>());
         let total_count = string.chars().count();
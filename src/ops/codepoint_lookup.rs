@@ -1,21 +1,28 @@
 use crate::auxiliary;
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output,OutputValue};
 use crate::range;
 
 pub struct CodepointLookup {}
 
-impl traits::Op for CodepointLookup {
-    fn name() -> &'static str { "codepoint-lookup" }
-    fn usage() -> &'static str { "<#1 string unicode-codepoint-name>" }
-    fn description() -> &'static str { "given the Unicode name as string #1 (e.g. “LATIN SMALL LETTER A”), return its UTF-8 representation (or an empty string, if unknown)" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.26) }
+impl Op for CodepointLookup {
+    fn name(&self) -> &'static str { "codepoint-lookup" }
+    fn usage(&self) -> &'static str { "<#1 string unicode-codepoint-name>" }
+    fn description(&self) -> &'static str { "given the Unicode name as string #1 (e.g. “LATIN SMALL LETTER A”), return its UTF-8 representation (or an empty string, if unknown)" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["LATIN SMALL LETTER A"], "a".into()),
+            Example::new(&["SNOWMAN"], "☃".into()),
+        ]
+    }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.26) }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let name = string.trim();
         let character = auxiliary::unicode_name_to_codepoint(name);
@@ -1,24 +1,31 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output,OutputValue};
 use crate::range;
 
 pub struct Codepoints {}
 
-impl traits::Op for Codepoints {
-    fn name() -> &'static str { "codepoints" }
-    fn usage() -> &'static str { "<#1 string to-decompose-and-represent>" }
-    fn description() -> &'static str { "represent string #1 with Unicode codepoints as integers, e.g. [72, 105, 10069]" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Codepoints {
+    fn name(&self) -> &'static str { "codepoints" }
+    fn usage(&self) -> &'static str { "<#1 string to-decompose-and-represent>" }
+    fn description(&self) -> &'static str { "represent string #1 with Unicode codepoints as integers, e.g. [72, 105, 10069]" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["Hi"], Output::HomogeneousList { data: vec![OutputValue::Int(72), OutputValue::Int(105)], notes: vec![] }),
+            Example::new(&["ä"], Output::HomogeneousList { data: vec![OutputValue::Int(228)], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s.len() / 10 > 50 { 0.2 } else { 0.5 })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let ints = string.chars().map(|c| OutputValue::Int(c as i64)).collect::<Vec<OutputValue>>();
         Ok(Output::HomogeneousList { data: ints, notes: vec![] })
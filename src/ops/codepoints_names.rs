@@ -1,21 +1,28 @@
 use crate::auxiliary;
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output,OutputValue};
 use crate::range;
 
 pub struct CodepointsNames {}
 
-impl traits::Op for CodepointsNames {
-    fn name() -> &'static str { "codepoints-names" }
-    fn usage() -> &'static str { "<#1 string to-decompose-and-represent>" }
-    fn description() -> &'static str { "look up the Unicode name (or 'unknown-name' if unknown) of each codepoint of string #1, e.g. [“LATIN SMALL LETTER H”, “LATIN SMALL LETTER DOTLESS ”]" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.39) }
+impl Op for CodepointsNames {
+    fn name(&self) -> &'static str { "codepoints-names" }
+    fn usage(&self) -> &'static str { "<#1 string to-decompose-and-represent>" }
+    fn description(&self) -> &'static str { "look up the Unicode name (or 'unknown-name' if unknown) of each codepoint of string #1, e.g. [“LATIN SMALL LETTER H”, “LATIN SMALL LETTER DOTLESS ”]" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["Hi"], Output::HomogeneousList { data: vec![OutputValue::from_str("LATIN CAPITAL LETTER H"), OutputValue::from_str("LATIN SMALL LETTER I")], notes: vec![] }),
+            Example::new(&["€"], Output::HomogeneousList { data: vec![OutputValue::from_str("EURO SIGN")], notes: vec![] }),
+        ]
+    }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.39) }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let mut unknowns = 0;
         let mut data = vec![];
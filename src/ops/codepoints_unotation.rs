@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output,OutputValue};
 use crate::range;
 
@@ -13,18 +14,24 @@ impl CodepointsUNotation {
     }
 }
 
-impl traits::Op for CodepointsUNotation {
-    fn name() -> &'static str { "codepoints-unotation" }
-    fn usage() -> &'static str { "<#1 string to-decompose-and-represent>" }
-    fn description() -> &'static str { "represent string #1 with Unicode codepoints, e.g. [“U+0048”, “U+0069”]" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for CodepointsUNotation {
+    fn name(&self) -> &'static str { "codepoints-unotation" }
+    fn usage(&self) -> &'static str { "<#1 string to-decompose-and-represent>" }
+    fn description(&self) -> &'static str { "represent string #1 with Unicode codepoints, e.g. [“U+0048”, “U+0069”]" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["Hi"], Output::HomogeneousList { data: vec![OutputValue::from_str("U+0048"), OutputValue::from_str("U+0069")], notes: vec![] }),
+            Example::new(&["€"], Output::HomogeneousList { data: vec![OutputValue::from_str("U+20AC")], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s.len() / 10 > 50 { 0.2 } else { 0.5 })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let arg: &str = args.get(0)?.try_into()?;
         let ustrings = Self::function_for_chars(arg.into());
         let mut uvalues = vec![];
@@ -1,7 +1,7 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
@@ -22,15 +22,15 @@ for i in sorted(combining_characters_by_wikipedia):
 // TODO review
 pub struct CombiningCodepointList {}
 
-impl traits::Op for CombiningCodepointList {
-    fn name() -> &'static str { "combining-codepoint-list" }
-    fn usage() -> &'static str { "" }
-    fn description() -> &'static str { "list all codepoints with a combining property" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(0, 0) }
+impl Op for CombiningCodepointList {
+    fn name(&self) -> &'static str { "combining-codepoint-list" }
+    fn usage(&self) -> &'static str { "" }
+    fn description(&self) -> &'static str { "list all codepoints with a combining property" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(0, 0) }
 
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.01) }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.01) }
 
-    fn run(_args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, _args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s = |s: &str| { OutputValue::SingleLineText(s.to_owned()) };
         let data = vec![
             vec![s("U+0300"), s("COMBINING GRAVE ACCENT")],
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -27,13 +28,19 @@ impl Concatenate {
     }
 }
 
-impl traits::Op for Concatenate {
-    fn name() -> &'static str { "concatenate" }
-    fn usage() -> &'static str { "<#1 string to-concatenate> 0 to … times" }
-    fn description() -> &'static str { "concatenate all provided strings" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(0) }
+impl Op for Concatenate {
+    fn name(&self) -> &'static str { "concatenate" }
+    fn usage(&self) -> &'static str { "<#1 string to-concatenate> 0 to … times" }
+    fn description(&self) -> &'static str { "concatenate all provided strings" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(0) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello", " ", "world"], "hello world".into()),
+            Example::new(&["a", "b"], "ab".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         Ok(match args.len() {
             0 | 1 => 0.0,
             2 => 0.34,
@@ -41,7 +48,7 @@ impl traits::Op for Concatenate {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let mut arguments = vec![];
         for arg in args.iter() {
             let s: &str = arg.try_into()?;
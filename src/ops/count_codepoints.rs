@@ -1,21 +1,39 @@
+use std::io;
+
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
+use crate::stream;
 
 pub struct CountCodepoints {}
 
-impl traits::Op for CountCodepoints {
-    fn name() -> &'static str { "count-codepoints" }
-    fn usage() -> &'static str { "<#1 string to-analyze>" }
-    fn description() -> &'static str { "return the number of Unicode scalars in the Unicode string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.67) }
+impl Op for CountCodepoints {
+    fn name(&self) -> &'static str { "count-codepoints" }
+    fn usage(&self) -> &'static str { "<#1 string to-analyze>" }
+    fn description(&self) -> &'static str { "return the number of Unicode scalars in the Unicode string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], 5usize.into()),
+            Example::new(&["äöü"], 3usize.into()),
+        ]
+    }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.67) }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(s.chars().count().into())
     }
+
+    fn streaming(&self) -> bool { true }
+
+    fn run_stream(&self, input: &mut dyn io::Read, _conf: &Configuration) -> Result<Output, LibError> {
+        let mut count = 0usize;
+        stream::for_each_str_chunk(input, |s| count += s.chars().count())?;
+        Ok(count.into())
+    }
 }
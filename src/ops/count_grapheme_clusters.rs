@@ -1,6 +1,8 @@
 use crate::config::Configuration;
-use crate::errors::LibError;use crate::input::Args;
-use crate::ops::traits;
+use crate::errors::LibError;
+use crate::example::Example;
+use crate::input::Args;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
@@ -8,16 +10,22 @@ use unicode_segmentation::UnicodeSegmentation;
 
 pub struct CountGraphemeClusters {}
 
-impl traits::Op for CountGraphemeClusters {
-    fn name() -> &'static str { "count-grapheme-clusters" }
-    fn usage() -> &'static str { "<#1 string to-analyze>" }
-    fn description() -> &'static str { "return number of “Grapheme clusters” in string #1 according to Unicode Standard Annex 29 “Unicode Text Segmentation”" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+impl Op for CountGraphemeClusters {
+    fn name(&self) -> &'static str { "count-grapheme-clusters" }
+    fn usage(&self) -> &'static str { "<#1 string to-analyze>" }
+    fn description(&self) -> &'static str { "return number of “Grapheme clusters” in string #1 according to Unicode Standard Annex 29 “Unicode Text Segmentation”" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], 5usize.into()),
+            Example::new(&["e\u{301}"], 1usize.into()),
+        ]
+    }
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         Ok(if string.len() >= 20 { 0.512 } else { 0.08 })
     }
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let count = UnicodeSegmentation::graphemes(string, true).map(OutputValue::from_str).count();
         Ok(count.into())
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct CountSubstring {}
 
-impl traits::Op for CountSubstring {
-    fn name() -> &'static str { "count-substring" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string search>" }
-    fn description() -> &'static str { "how often does string #2 non-overlappingly occur in string #1?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for CountSubstring {
+    fn name(&self) -> &'static str { "count-substring" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string search>" }
+    fn description(&self) -> &'static str { "how often does string #2 non-overlappingly occur in string #1?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["banana", "an"], 2usize.into()),
+            Example::new(&["aaaa", "aa"], 2usize.into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let substring: &str = args.get(1)?.try_into()?;
         Ok(if string.find(substring).is_some() {
@@ -23,7 +30,7 @@ impl traits::Op for CountSubstring {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let substring: &str = args.get(1)?.try_into()?;
         Ok(string.matches(substring).count().into())
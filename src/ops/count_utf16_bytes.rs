@@ -1,21 +1,42 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct CountUtf16Bytes {}
 
-impl traits::Op for CountUtf16Bytes {
-    fn name() -> &'static str { "count-utf16-bytes" }
-    fn usage() -> &'static str { "<#1 string to-analyze>" }
-    fn description() -> &'static str { "encode string #1 in UTF-16 and return its number of bytes" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.56) }
+impl Op for CountUtf16Bytes {
+    fn name(&self) -> &'static str { "count-utf16-bytes" }
+    fn usage(&self) -> &'static str { "<#1 string to-analyze>" }
+    fn description(&self) -> &'static str { "encode string #1 in UTF-16 and return its number of bytes" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], 10usize.into()),
+            Example::new(&["😇"], 4usize.into()),
+        ]
+    }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.56) }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s: &str = args.get(0)?.try_into()?;
-        Ok(s.encode_utf16().count().into())
+        Ok((2 * s.encode_utf16().count()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Arg;
+    use crate::registry::Op;
+
+    #[test]
+    fn test_count_utf16_bytes() {
+        let conf = Configuration::default();
+        assert_eq!(CountUtf16Bytes {}.run(&Args::from(&[Arg::from_str("hello", 0)]), &conf).unwrap(), Output::from(10usize));
+        assert_eq!(CountUtf16Bytes {}.run(&Args::from(&[Arg::from_str("😇", 0)]), &conf).unwrap(), Output::from(4usize));
     }
 }
@@ -1,21 +1,39 @@
+use std::io;
+
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
+use crate::stream;
 
 pub struct CountUtf8Bytes {}
 
-impl traits::Op for CountUtf8Bytes {
-    fn name() -> &'static str { "count-utf8-bytes" }
-    fn usage() -> &'static str { "<#1 string to-analyze>" }
-    fn description() -> &'static str { "encode string #1 in UTF-8 and return its number of bytes" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.56) }
+impl Op for CountUtf8Bytes {
+    fn name(&self) -> &'static str { "count-utf8-bytes" }
+    fn usage(&self) -> &'static str { "<#1 string-or-bytes to-analyze>" }
+    fn description(&self) -> &'static str { "encode string #1 in UTF-8 and return its number of bytes (or return the number of bytes #1)" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], 5usize.into()),
+            Example::new(&["äöü"], 6usize.into()),
+        ]
+    }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.56) }
+
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        let bytes: &[u8] = args.get(0)?.try_into()?;
+        Ok(bytes.len().into())
+    }
+
+    fn streaming(&self) -> bool { true }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
-        let s: &str = args.get(0)?.try_into()?;
-        Ok(s.bytes().count().into())
+    fn run_stream(&self, input: &mut dyn io::Read, _conf: &Configuration) -> Result<Output, LibError> {
+        let mut count = 0usize;
+        stream::for_each_chunk(input, |chunk| { count += chunk.len(); Ok(()) })?;
+        Ok(count.into())
     }
 }
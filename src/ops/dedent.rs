@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -34,13 +35,19 @@ impl Dedent {
     }
 }
 
-impl traits::Op for Dedent {
-    fn name() -> &'static str { "dedent" }
-    fn usage() -> &'static str { "<#1 string lines>" }
-    fn description() -> &'static str { "identify and remove common indentation among all non-empty lines of string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Dedent {
+    fn name(&self) -> &'static str { "dedent" }
+    fn usage(&self) -> &'static str { "<#1 string lines>" }
+    fn description(&self) -> &'static str { "identify and remove common indentation among all non-empty lines of string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a\n  b"], "a\n  b".into()),
+            Example::new(&["x"], "x".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let common_prefix = Self::identify_common_prefix_on_nonempty_lines(string);
 
@@ -53,7 +60,7 @@ impl traits::Op for Dedent {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let common_prefix = Self::identify_common_prefix_on_nonempty_lines(string);
 
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct DedentWithSubstring {}
 
-impl traits::Op for DedentWithSubstring {
-    fn name() -> &'static str { "dedent-with-substring" }
-    fn usage() -> &'static str { "<#1 string lines> <#2 string prefix-to-remove>" }
-    fn description() -> &'static str { "remove prefix string #2 at the beginning of every line of string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for DedentWithSubstring {
+    fn name(&self) -> &'static str { "dedent-with-substring" }
+    fn usage(&self) -> &'static str { "<#1 string lines> <#2 string prefix-to-remove>" }
+    fn description(&self) -> &'static str { "remove prefix string #2 at the beginning of every line of string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["> a\n> b", "> "], "a\nb".into()),
+            Example::new(&["//x\n//y", "//"], "x\ny".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let indentation: &str = args.get(1)?.try_into()?;
 
@@ -22,7 +29,7 @@ impl traits::Op for DedentWithSubstring {
         Ok(prio1 * prio2)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let indentation: &str = args.get(1)?.try_into()?;
 
@@ -1,9 +1,13 @@
+use std::io;
+
 use crate::config::Configuration;
 use crate::errors::LibError;
-use crate::input::Args;
-use crate::ops::traits;
+use crate::example::Example;
+use crate::input::{Arg, Args};
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
+use crate::stream;
 
 #[cfg(feature = "digest")]
 use hex::ToHex;
@@ -18,26 +22,41 @@ impl DigestMd5 {
         md5::compute(arg.as_bytes()).encode_hex()
     }
 
-    #[doc(hidden)]
-    #[allow(dead_code)]
     fn function_for_bytes(arg: &[u8]) -> String {
         md5::compute(arg).encode_hex()
     }
 }
 
-impl traits::Op for DigestMd5 {
-    fn name() -> &'static str { "digest-md5" }
-    fn usage() -> &'static str { "<#1 string to-digest>" }
-    fn description() -> &'static str { "generate the MD5 hexadecimal digest of the given UTF-8 string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for DigestMd5 {
+    fn name(&self) -> &'static str { "digest-md5" }
+    fn usage(&self) -> &'static str { "<#1 string-or-bytes to-digest>" }
+    fn description(&self) -> &'static str { "generate the MD5 hexadecimal digest of the given UTF-8 string or bytes #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn feature(&self) -> Option<&'static str> { Some("digest") }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], "5d41402abc4b2a76b9719d911017c592".into()),
+            Example::new(&[""], "d41d8cd98f00b204e9800998ecf8427e".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
-        let s: &str = args.get(0)?.try_into()?;
-        Ok(if s.len() <= 3 { 0.22 } else { 0.42 })
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+        let bytes: &[u8] = args.get(0)?.try_into()?;
+        Ok(if bytes.len() <= 3 { 0.22 } else { 0.42 })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
-        let arg: &str = args.get(0)?.try_into()?;
-        Ok(Self::function_for_chars(arg).into())
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        Ok(match args.get(0)? {
+            Arg::Chars(s, _) => Self::function_for_chars(s),
+            Arg::Bytes(b, _) => Self::function_for_bytes(b),
+        }.into())
+    }
+
+    fn streaming(&self) -> bool { true }
+
+    fn run_stream(&self, input: &mut dyn io::Read, _conf: &Configuration) -> Result<Output, LibError> {
+        let mut context = md5::Context::new();
+        stream::for_each_chunk(input, |chunk| { context.consume(chunk); Ok(()) })?;
+        Ok(context.compute().encode_hex::<String>().into())
     }
 }
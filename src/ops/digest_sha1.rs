@@ -1,9 +1,13 @@
+use std::io;
+
 use crate::config::Configuration;
 use crate::errors::LibError;
-use crate::input::Args;
-use crate::ops::traits;
+use crate::example::Example;
+use crate::input::{Arg, Args};
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
+use crate::stream;
 
 #[cfg(feature = "digest")]
 use hex::ToHex;
@@ -20,8 +24,6 @@ impl DigestSha1 {
         hasher.finalize().encode_hex()
     }
 
-    #[doc(hidden)]
-    #[allow(dead_code)]
     fn function_for_bytes(arg: &[u8]) -> String {
         let mut hasher = Sha1::new();
         hasher.update(arg);
@@ -29,19 +31,36 @@ impl DigestSha1 {
     }
 }
 
-impl traits::Op for DigestSha1 {
-    fn name() -> &'static str { "digest-sha1" }
-    fn usage() -> &'static str { "<#1 string to-digest>" }
-    fn description() -> &'static str { "generate the SHA1 hexadecimal digest of the given UTF-8 string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for DigestSha1 {
+    fn name(&self) -> &'static str { "digest-sha1" }
+    fn usage(&self) -> &'static str { "<#1 string-or-bytes to-digest>" }
+    fn description(&self) -> &'static str { "generate the SHA1 hexadecimal digest of the given UTF-8 string or bytes #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn feature(&self) -> Option<&'static str> { Some("digest") }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d".into()),
+            Example::new(&[""], "da39a3ee5e6b4b0d3255bfef95601890afd80709".into()),
+        ]
+    }
+
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+        let bytes: &[u8] = args.get(0)?.try_into()?;
+        Ok(if bytes.len() <= 3 { 0.21 } else { 0.41 })
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
-        let s: &str = args.get(0)?.try_into()?;
-        Ok(if s.len() <= 3 { 0.21 } else { 0.41 })
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        Ok(match args.get(0)? {
+            Arg::Chars(s, _) => Self::function_for_chars(s),
+            Arg::Bytes(b, _) => Self::function_for_bytes(b),
+        }.into())
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
-        let arg: &str = args.get(0)?.try_into()?;
-        Ok(Self::function_for_chars(arg).into())
+    fn streaming(&self) -> bool { true }
+
+    fn run_stream(&self, input: &mut dyn io::Read, _conf: &Configuration) -> Result<Output, LibError> {
+        let mut hasher = Sha1::new();
+        stream::for_each_chunk(input, |chunk| { hasher.update(chunk); Ok(()) })?;
+        Ok(hasher.finalize().encode_hex::<String>().into())
     }
 }
@@ -1,9 +1,13 @@
+use std::io;
+
 use crate::config::Configuration;
 use crate::errors::LibError;
-use crate::input::Args;
-use crate::ops::traits;
+use crate::example::Example;
+use crate::input::{Arg, Args};
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
+use crate::stream;
 
 #[cfg(feature = "digest")]
 use hex::ToHex;
@@ -20,8 +24,6 @@ impl DigestSha256 {
         hasher.finalize().encode_hex()
     }
 
-    #[doc(hidden)]
-    #[allow(dead_code)]
     fn function_for_bytes(arg: &[u8]) -> String {
         let mut hasher = Sha256::new();
         hasher.update(arg);
@@ -29,19 +31,36 @@ impl DigestSha256 {
     }
 }
 
-impl traits::Op for DigestSha256 {
-    fn name() -> &'static str { "digest-sha256" }
-    fn usage() -> &'static str { "<#1 string to-digest>" }
-    fn description() -> &'static str { "generate the SHA256 hexadecimal digest of the given UTF-8 string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for DigestSha256 {
+    fn name(&self) -> &'static str { "digest-sha256" }
+    fn usage(&self) -> &'static str { "<#1 string-or-bytes to-digest>" }
+    fn description(&self) -> &'static str { "generate the SHA256 hexadecimal digest of the given UTF-8 string or bytes #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn feature(&self) -> Option<&'static str> { Some("digest") }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824".into()),
+            Example::new(&[""], "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".into()),
+        ]
+    }
+
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+        let bytes: &[u8] = args.get(0)?.try_into()?;
+        Ok(if bytes.len() <= 3 { 0.24 } else { 0.44 })
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
-        let s: &str = args.get(0)?.try_into()?;
-        Ok(if s.len() <= 3 { 0.24 } else { 0.44 })
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        Ok(match args.get(0)? {
+            Arg::Chars(s, _) => Self::function_for_chars(s),
+            Arg::Bytes(b, _) => Self::function_for_bytes(b),
+        }.into())
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
-        let arg: &str = args.get(0)?.try_into()?;
-        Ok(Self::function_for_chars(arg).into())
+    fn streaming(&self) -> bool { true }
+
+    fn run_stream(&self, input: &mut dyn io::Read, _conf: &Configuration) -> Result<Output, LibError> {
+        let mut hasher = Sha256::new();
+        stream::for_each_chunk(input, |chunk| { hasher.update(chunk); Ok(()) })?;
+        Ok(hasher.finalize().encode_hex::<String>().into())
     }
 }
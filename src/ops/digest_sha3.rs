@@ -1,9 +1,13 @@
+use std::io;
+
 use crate::config::Configuration;
 use crate::errors::LibError;
-use crate::input::Args;
-use crate::ops::traits;
+use crate::example::Example;
+use crate::input::{Arg, Args};
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
+use crate::stream;
 
 #[cfg(feature = "digest")]
 use hex::ToHex;
@@ -20,8 +24,6 @@ impl DigestSha3256 {
         hasher.finalize().encode_hex()
     }
 
-    #[doc(hidden)]
-    #[allow(dead_code)]
     fn function_for_bytes(arg: &[u8]) -> String {
         let mut hasher = Sha3_256::new();
         hasher.update(arg);
@@ -29,19 +31,36 @@ impl DigestSha3256 {
     }
 }
 
-impl traits::Op for DigestSha3256 {
-    fn name() -> &'static str { "digest-sha3-256" }
-    fn usage() -> &'static str { "<#1 string to-digest>" }
-    fn description() -> &'static str { "generate the SHA3-256 hexadecimal digest of the given UTF-8 string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for DigestSha3256 {
+    fn name(&self) -> &'static str { "digest-sha3-256" }
+    fn usage(&self) -> &'static str { "<#1 string-or-bytes to-digest>" }
+    fn description(&self) -> &'static str { "generate the SHA3-256 hexadecimal digest of the given UTF-8 string or bytes #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn feature(&self) -> Option<&'static str> { Some("digest") }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], "3338be694f50c5f338814986cdf0686453a888b84f424d792af4b9202398f392".into()),
+            Example::new(&[""], "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a".into()),
+        ]
+    }
+
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+        let bytes: &[u8] = args.get(0)?.try_into()?;
+        Ok(if bytes.len() <= 3 { 0.23 } else { 0.43 })
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
-        let s: &str = args.get(0)?.try_into()?;
-        Ok(if s.len() <= 3 { 0.23 } else { 0.43 })
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        Ok(match args.get(0)? {
+            Arg::Chars(s, _) => Self::function_for_chars(s),
+            Arg::Bytes(b, _) => Self::function_for_bytes(b),
+        }.into())
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
-        let arg: &str = args.get(0)?.try_into()?;
-        Ok(Self::function_for_chars(arg).into())
+    fn streaming(&self) -> bool { true }
+
+    fn run_stream(&self, input: &mut dyn io::Read, _conf: &Configuration) -> Result<Output, LibError> {
+        let mut hasher = Sha3_256::new();
+        stream::for_each_chunk(input, |chunk| { hasher.update(chunk); Ok(()) })?;
+        Ok(hasher.finalize().encode_hex::<String>().into())
     }
 }
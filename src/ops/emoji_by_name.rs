@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -36,17 +37,23 @@ impl EmojiByName {
             }
         }
 
-        Err(LibError::ArgValueError(arg_id, format!("Unknown emoji identifier: '{}'", arg)))
+        Err(LibError::OpError(arg_id, "unknown-emoji", format!("Unknown emoji identifier: '{}'", arg)))
     }
 }
 
-impl traits::Op for EmojiByName {
-    fn name() -> &'static str { "emoji-by-name" }
-    fn usage() -> &'static str { "<#1 string emoji-description>" }
-    fn description() -> &'static str { "given a Emoji Sequence Data (UTS #51) description string #1 return the corresponding emoji (e.g. 'smiling face with halo' returns '😇')" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for EmojiByName {
+    fn name(&self) -> &'static str { "emoji-by-name" }
+    fn usage(&self) -> &'static str { "<#1 string emoji-description>" }
+    fn description(&self) -> &'static str { "given a Emoji Sequence Data (UTS #51) description string #1 return the corresponding emoji (e.g. 'smiling face with halo' returns '😇')" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["smiling face with halo"], "😇".into()),
+            Example::new(&["snowman"], "☃\u{fe0f}".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let name: &str = args.get(0)?.try_into()?;
         Ok(if Self::function_for_chars(name, 0).is_ok() {
             1.0
@@ -55,7 +62,7 @@ impl traits::Op for EmojiByName {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let name: &str = args.get(0)?.try_into()?;
         Ok(Self::function_for_chars(name, 0)?.into())
     }
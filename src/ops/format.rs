@@ -1,8 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
-use crate::ops::traits::Op;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -17,7 +17,7 @@ pub struct Format {}
 impl Format {
     fn function_for_chars(args: &[&str]) -> Result<String, LibError> {
         match args.len() {
-            0 => Err(LibError::ArgumentCountError(Self::acceptable_number_of_arguments(), 0, None)),
+            0 => Err(LibError::ArgumentCountError(Format {}.acceptable_number_of_arguments(), 0, None)),
             1 => Ok(args[0].into()),
             _ => {
                 let template: &str = args[0];
@@ -33,7 +33,7 @@ impl Format {
 
                 let repr = match ParsedFormat::parse(template, &fmt_args, &rt_format::NoNamedArguments) {
                     Ok(val) => val.to_string(),
-                    Err(failing_pos) => return Err(LibError::ArgValueError(0, format!("format string is invalid at zero-based position {}", failing_pos))),
+                    Err(failing_pos) => return Err(LibError::OpError(0, "invalid-format-string", format!("format string is invalid at zero-based position {}", failing_pos))),
                 };
 
                 Ok(repr)
@@ -42,13 +42,19 @@ impl Format {
     }
 }
 
-impl traits::Op for Format {
-    fn name() -> &'static str { "format" }
-    fn usage() -> &'static str { "<#1 string format-with-placeholders> [<#2 string arg> 0 or more times]" }
-    fn description() -> &'static str { "replace {placeholders} in string #1 with consecutive arguments #2, #3, …" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(1) }
+impl Op for Format {
+    fn name(&self) -> &'static str { "format" }
+    fn usage(&self) -> &'static str { "<#1 string format-with-placeholders> [<#2 string arg> 0 or more times]" }
+    fn description(&self) -> &'static str { "replace {placeholders} in string #1 with consecutive arguments #2, #3, …" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["{} + {}", "1", "2"], "1 + 2".into()),
+            Example::new(&["hello {}!", "world"], "hello world!".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let template: &str = args.get(0)?.try_into()?;
         let occurences_start = template.matches('{').count().max(5);
         let occurences_end = template.matches('}').count().max(5);
@@ -65,7 +71,7 @@ impl traits::Op for Format {
         Ok(score)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         match args.len() {
             0 => Ok("".into()),
             1 => {
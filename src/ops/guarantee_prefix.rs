@@ -1,21 +1,28 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct GuaranteePrefix {}
 
-impl traits::Op for GuaranteePrefix {
-    fn name() -> &'static str { "guarantee-prefix" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string prefix>" }
-    fn description() -> &'static str { "if string #1 does not start with string #2, prepend it" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for GuaranteePrefix {
+    fn name(&self) -> &'static str { "guarantee-prefix" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string prefix>" }
+    fn description(&self) -> &'static str { "if string #1 does not start with string #2, prepend it" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["example.org", "https://"], "https://example.org".into()),
+            Example::new(&["https://example.org", "https://"], "https://example.org".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let base: &str = args.get(0)?.try_into()?;
-        let prefix: &str = args.get(0)?.try_into()?;
+        let prefix: &str = args.get(1)?.try_into()?;
         Ok(if base.len() > prefix.len() {
             0.52
         } else {
@@ -23,9 +30,9 @@ impl traits::Op for GuaranteePrefix {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let base: &str = args.get(0)?.try_into()?;
-        let prefix: &str = args.get(0)?.try_into()?;
+        let prefix: &str = args.get(1)?.try_into()?;
         if base.starts_with(prefix) {
             Ok(base.into())
         } else {
@@ -35,3 +42,19 @@ impl traits::Op for GuaranteePrefix {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Arg;
+    use crate::registry::Op;
+
+    #[test]
+    fn test_guarantee_prefix() {
+        let conf = Configuration::default();
+        let args = Args::from(&[Arg::from_str("example.org", 0), Arg::from_str("https://", 1)]);
+        assert_eq!(GuaranteePrefix {}.run(&args, &conf).unwrap(), Output::from("https://example.org"));
+        let args = Args::from(&[Arg::from_str("https://example.org", 0), Arg::from_str("https://", 1)]);
+        assert_eq!(GuaranteePrefix {}.run(&args, &conf).unwrap(), Output::from("https://example.org"));
+    }
+}
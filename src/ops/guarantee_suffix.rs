@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct GuaranteeSuffix {}
 
-impl traits::Op for GuaranteeSuffix {
-    fn name() -> &'static str { "guarantee-suffix" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string suffix>" }
-    fn description() -> &'static str { "if string #1 does not end with string #2, append it" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for GuaranteeSuffix {
+    fn name(&self) -> &'static str { "guarantee-suffix" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string suffix>" }
+    fn description(&self) -> &'static str { "if string #1 does not end with string #2, append it" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["path", "/"], "path/".into()),
+            Example::new(&["path/", "/"], "path/".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let base: &str = args.get(0)?.try_into()?;
         let suffix: &str = args.get(1)?.try_into()?;
         Ok(if base.len() > suffix.len() {
@@ -23,7 +30,7 @@ impl traits::Op for GuaranteeSuffix {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let base: &str = args.get(0)?.try_into()?;
         let suffix: &str = args.get(1)?.try_into()?;
 
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -24,13 +25,19 @@ impl HumanReadableBytes {
     }
 }
 
-impl traits::Op for HumanReadableBytes {
-    fn name() -> &'static str { "human-readable-bytes" }
-    fn usage() -> &'static str { "<#1 string bytes-count>" }
-    fn description() -> &'static str { "represent integer #1 (as 1024-based count of bytes) in a human-readable manner likely with two decimal points" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for HumanReadableBytes {
+    fn name(&self) -> &'static str { "human-readable-bytes" }
+    fn usage(&self) -> &'static str { "<#1 int bytes-count>" }
+    fn description(&self) -> &'static str { "represent integer #1 (as 1024-based count of bytes) in a human-readable manner likely with two decimal points" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["1024"], "1 kB".into()),
+            Example::new(&["1500000"], "1.43 MB".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let res: Result<i64, LibError> = args.get(0)?.try_into();
         Ok(match res {
             Ok(bytes_count) => {
@@ -44,7 +51,7 @@ impl traits::Op for HumanReadableBytes {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let bytes_count: i64 = args.get(0)?.try_into()?;
         Ok(Self::function_for_i64(bytes_count).into())
     }
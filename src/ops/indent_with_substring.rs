@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct IndentWithSubstring {}
 
-impl traits::Op for IndentWithSubstring {
-    fn name() -> &'static str { "indent-with-substring" }
-    fn usage() -> &'static str { "<#1 string lines> <#2 string prefix-to-attach>" }
-    fn description() -> &'static str { "concatenate string #2 with every non-empty line in string #1, keep other lines" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for IndentWithSubstring {
+    fn name(&self) -> &'static str { "indent-with-substring" }
+    fn usage(&self) -> &'static str { "<#1 string lines> <#2 string prefix-to-attach>" }
+    fn description(&self) -> &'static str { "concatenate string #2 with every non-empty line in string #1, keep other lines" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a\nb", "> "], "> a\n> b".into()),
+            Example::new(&["a\n\nb", "  "], "  a\n\n  b".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let indentation: &str = args.get(1)?.try_into()?;
 
@@ -22,7 +29,7 @@ impl traits::Op for IndentWithSubstring {
         Ok(prio1 * prio2)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let indentation: &str = args.get(1)?.try_into()?;
 
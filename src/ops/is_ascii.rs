@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct IsAscii {}
 
-impl traits::Op for IsAscii {
-    fn name() -> &'static str { "is-ascii" }
-    fn usage() -> &'static str { "<#1 string to-analyze>" }
-    fn description() -> &'static str { "does this string #1 only contain ASCII characters?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for IsAscii {
+    fn name(&self) -> &'static str { "is-ascii" }
+    fn usage(&self) -> &'static str { "<#1 string to-analyze>" }
+    fn description(&self) -> &'static str { "does this string #1 only contain ASCII characters?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello"], true.into()),
+            Example::new(&["h\u{e9}llo"], false.into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s.is_ascii() {
             0.476
@@ -22,7 +29,7 @@ impl traits::Op for IsAscii {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(s.is_ascii().into())
     }
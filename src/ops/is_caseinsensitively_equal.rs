@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -9,13 +10,19 @@ use crate::range;
 
 pub struct IsCaseinsensitivelyEqual {}
 
-impl traits::Op for IsCaseinsensitivelyEqual {
-    fn name() -> &'static str { "is-caseinsensitively-equal" }
-    fn usage() -> &'static str { "<#1 string base> [<#2 string compare> 1 or more times]" }
-    fn description() -> &'static str { "do all Unicode strings have the same byte sequence after ASCII lowercasing?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(2) }
+impl Op for IsCaseinsensitivelyEqual {
+    fn name(&self) -> &'static str { "is-caseinsensitively-equal" }
+    fn usage(&self) -> &'static str { "<#1 string base> [<#2 string compare> 1 or more times]" }
+    fn description(&self) -> &'static str { "do all Unicode strings have the same byte sequence after ASCII lowercasing?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["Hello", "hELLO"], true.into()),
+            Example::new(&["Hello", "World"], false.into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let mut eq = true;
         let str1: &str = args.get(0)?.try_into()?;
         let s1 = str1.to_ascii_lowercase();
@@ -30,7 +37,7 @@ impl traits::Op for IsCaseinsensitivelyEqual {
         Ok(if eq { 0.64 } else { 0.52 })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let mut eq = true;
         let str1: &str = args.get(0)?.try_into()?;
         let s1 = str1.to_ascii_lowercase();
@@ -5,7 +5,7 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -31,13 +31,13 @@ impl IsCharsetID {
     }
 }
 
-impl traits::Op for IsCharsetID {
-    fn name() -> &'static str { "is-charset-id" }
-    fn usage() -> &'static str { "<#1 string to-encode>" }
-    fn description() -> &'static str { "is the given name argument #1 a valid charset identifier?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for IsCharsetID {
+    fn name(&self) -> &'static str { "is-charset-id" }
+    fn usage(&self) -> &'static str { "<#1 string to-encode>" }
+    fn description(&self) -> &'static str { "is the given name argument #1 a valid charset identifier?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let candidate: &str = args.get(0)?.try_into()?;
 
         Ok(match Self::lookup(candidate) {
@@ -53,7 +53,7 @@ impl traits::Op for IsCharsetID {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let candidate: &str = args.get(0)?.try_into()?;
 
         match Self::lookup(candidate) {
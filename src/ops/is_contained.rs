@@ -1,25 +1,46 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct IsContained {}
 
-impl traits::Op for IsContained {
-    fn name() -> &'static str { "is-contained" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string contained>" }
-    fn description() -> &'static str { "does string #1 contain string #2?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for IsContained {
+    fn name(&self) -> &'static str { "is-contained" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string contained>" }
+    fn description(&self) -> &'static str { "does string #1 contain string #2?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello world", "lo w"], true.into()),
+            Example::new(&["hello", "x"], false.into()),
+        ]
+    }
 
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         Ok(0.564)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
         Ok(s1.contains(s2).into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Arg;
+    use crate::registry::Op;
+
+    #[test]
+    fn test_is_contained() {
+        assert!(IsContained {}.acceptable_number_of_arguments().has(2));
+        let args = Args::from(&[Arg::from_str("hello world", 0), Arg::from_str("lo w", 1)]);
+        assert_eq!(IsContained {}.run(&args, &Configuration::default()).unwrap(), Output::from(true));
+    }
+}
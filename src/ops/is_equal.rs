@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct IsEqual {}
 
-impl traits::Op for IsEqual {
-    fn name() -> &'static str { "is-equal" }
-    fn usage() -> &'static str { "<#1 string base> [<#2 string compare> 1 or more times]" }
-    fn description() -> &'static str { "do all Unicode strings have the same byte sequence?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(2) }
+impl Op for IsEqual {
+    fn name(&self) -> &'static str { "is-equal" }
+    fn usage(&self) -> &'static str { "<#1 string base> [<#2 string compare> 1 or more times]" }
+    fn description(&self) -> &'static str { "do all Unicode strings have the same byte sequence?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["abc", "abc"], true.into()),
+            Example::new(&["abc", "abd"], false.into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let mut eq = true;
         let s1: &str = args.get(0)?.try_into()?;
 
@@ -27,7 +34,7 @@ impl traits::Op for IsEqual {
         Ok(if eq { 0.64 } else { 0.52 })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let mut eq = true;
         let s1: &str = args.get(0)?.try_into()?;
 
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct IsLFLineTerminated {}
 
-impl traits::Op for IsLFLineTerminated {
-    fn name() -> &'static str { "is-lf-lineterminated" }
-    fn usage() -> &'static str { "<#1 string lines>" }
-    fn description() -> &'static str { "is U+000A LINE FEED the only character causing line breaks in string #1?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for IsLFLineTerminated {
+    fn name(&self) -> &'static str { "is-lf-lineterminated" }
+    fn usage(&self) -> &'static str { "<#1 string lines>" }
+    fn description(&self) -> &'static str { "is U+000A LINE FEED the only character causing line breaks in string #1?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a\nb"], true.into()),
+            Example::new(&["a\r\nb"], false.into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         Ok(if string.lines().count() > 1 {
             0.12
@@ -22,7 +29,7 @@ impl traits::Op for IsLFLineTerminated {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         // line break causing characters can be found in categories c.f. UAX #14 …
         //   BK    Mandatory Break   Cause a line break (after)
         //   CR    Carriage Return   Cause a line break (after), except between CR and LF
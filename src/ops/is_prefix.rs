@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -23,13 +24,19 @@ impl IsPrefix {
     }
 }
 
-impl traits::Op for IsPrefix {
-    fn name() -> &'static str { "is-prefix" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string prefix>" }
-    fn description() -> &'static str { "does string #1 start with string #2?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for IsPrefix {
+    fn name(&self) -> &'static str { "is-prefix" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string prefix>" }
+    fn description(&self) -> &'static str { "does string #1 start with string #2?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello", "he"], true.into()),
+            Example::new(&["hello", "lo"], false.into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
         if s1.len() < s2.len() {
@@ -45,7 +52,7 @@ impl traits::Op for IsPrefix {
         } * p)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
         Ok(Self::function_for_chars(s1, s2).into())
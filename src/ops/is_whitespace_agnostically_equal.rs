@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -31,18 +32,24 @@ impl IsWhitespaceAgnosticallyEqual {
     }
 }
 
-impl traits::Op for IsWhitespaceAgnosticallyEqual {
-    fn name() -> &'static str { "is-whitespace-agnostically-equal" }
-    fn usage() -> &'static str { "<#1 string base> [<#2 string compare> 1 or more times]" }
-    fn description() -> &'static str { "are all strings equal if we ignore any whitespace characters?" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(2) }
+impl Op for IsWhitespaceAgnosticallyEqual {
+    fn name(&self) -> &'static str { "is-whitespace-agnostically-equal" }
+    fn usage(&self) -> &'static str { "<#1 string base> [<#2 string compare> 1 or more times]" }
+    fn description(&self) -> &'static str { "are all strings equal if we ignore any whitespace characters?" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a b c", "abc"], true.into()),
+            Example::new(&["a b", "a c"], false.into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         if args.len() <= 1 { return Ok(0.0); }
         Ok(if Self::function_for_args(args)? { 0.41 } else { 0.25 })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         Ok(Self::function_for_args(args)?.into())
     }
 }
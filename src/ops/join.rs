@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct Join {}
 
-impl traits::Op for Join {
-    fn name() -> &'static str { "join" }
-    fn usage() -> &'static str { "<#1 string separator> [<#2 string to-join> 0 or more times]" }
-    fn description() -> &'static str { "join all following strings with string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(1) }
+impl Op for Join {
+    fn name(&self) -> &'static str { "join" }
+    fn usage(&self) -> &'static str { "<#1 string separator> [<#2 string to-join> 0 or more times]" }
+    fn description(&self) -> &'static str { "join all following strings with string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&[", ", "a", "b", "c"], "a, b, c".into()),
+            Example::new(&["-", "x"], "x".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         Ok(match args.len() {
             0 => 0.0,
             1 => 0.09,
@@ -22,7 +29,7 @@ impl traits::Op for Join {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let sep = args.get(0)?.try_into()?;
         let mut result = String::new();
         if args.len() >= 2 {
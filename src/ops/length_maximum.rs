@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -19,13 +20,19 @@ impl LengthMaximum {
     }
 }
 
-impl traits::Op for LengthMaximum {
-    fn name() -> &'static str { "length-maximum" }
-    fn usage() -> &'static str { "[<#1 string to-convert> 1 or more times]" }
-    fn description() -> &'static str { "return the first string among the longest strings" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(1) }
+impl Op for LengthMaximum {
+    fn name(&self) -> &'static str { "length-maximum" }
+    fn usage(&self) -> &'static str { "[<#1 string to-convert> 1 or more times]" }
+    fn description(&self) -> &'static str { "return the first string among the longest strings" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a", "abc", "ab"], "abc".into()),
+            Example::new(&["xy", "zw"], "xy".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         Ok(if args.len() >= 3 {
             0.72
         } else {
@@ -33,7 +40,7 @@ impl traits::Op for LengthMaximum {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let mut arguments = vec![];
         for arg in args.iter() {
             let s: &str = arg.try_into()?;
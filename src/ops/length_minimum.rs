@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -21,13 +22,19 @@ impl LengthMinimum {
     }
 }
 
-impl traits::Op for LengthMinimum {
-    fn name() -> &'static str { "length-minimum" }
-    fn usage() -> &'static str { "[<#1 string to-convert> 1 or more times]" }
-    fn description() -> &'static str { "return the first string among the shortest strings" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(1) }
+impl Op for LengthMinimum {
+    fn name(&self) -> &'static str { "length-minimum" }
+    fn usage(&self) -> &'static str { "[<#1 string to-convert> 1 or more times]" }
+    fn description(&self) -> &'static str { "return the first string among the shortest strings" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["abc", "a", "ab"], "a".into()),
+            Example::new(&["xy", "zw"], "xy".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         Ok(if args.len() >= 3 {
             0.71
         } else {
@@ -35,7 +42,7 @@ impl traits::Op for LengthMinimum {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let mut arguments = vec![];
         for arg in args.iter() {
             let s: &str = arg.try_into()?;
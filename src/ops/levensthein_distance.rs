@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -11,7 +12,7 @@ pub struct LevenstheinDistance {}
 
 // via https://en.wikibooks.org/wiki/Algorithm_Implementation/Strings/Levenshtein_distance#Rust
 // CC BY-SA: https://creativecommons.org/licenses/by-sa/4.0/
-fn levenshtein_d1stance<T>(s1: &T, s2: &T) -> usize where T: ToString {
+pub(crate) fn levenshtein_d1stance<T>(s1: &T, s2: &T) -> usize where T: ToString {
     let v1: Vec<char> = s1.to_string().chars().collect();
     let v2: Vec<char> = s2.to_string().chars().collect();
     let v1len = v1.len();
@@ -43,15 +44,21 @@ fn levenshtein_d1stance<T>(s1: &T, s2: &T) -> usize where T: ToString {
     column[v1len]
 }
 
-impl traits::Op for LevenstheinDistance {
-    fn name() -> &'static str { "levensthein-distance" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string to-compare>" }
-    fn description() -> &'static str { "levensthein distance between strings #1 and #2" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for LevenstheinDistance {
+    fn name(&self) -> &'static str { "levensthein-distance" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string to-compare>" }
+    fn description(&self) -> &'static str { "levensthein distance between strings #1 and #2" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["kitten", "sitting"], 3usize.into()),
+            Example::new(&["abc", "abc"], 0usize.into()),
+        ]
+    }
 
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.473) }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.473) }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
 
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -154,13 +155,19 @@ impl LinebreakBefore {
     } 
 }
 
-impl traits::Op for LinebreakBefore {
-    fn name() -> &'static str { "linebreak-before" }
-    fn usage() -> &'static str { "<#1 string text> <#2 int width>" }
-    fn description() -> &'static str { "linebreak long lines in (text #1) before they reach (integer #2) codepoints" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for LinebreakBefore {
+    fn name(&self) -> &'static str { "linebreak-before" }
+    fn usage(&self) -> &'static str { "<#1 string text> <#2 int width>" }
+    fn description(&self) -> &'static str { "linebreak long lines in (text #1) before they reach (integer #2) codepoints" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello", "10"], "hello".into()),
+            Example::new(&["a b", "10"], "a b".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         let width: i64 = match args.get(1)?.try_into() {
             Ok(int) => int,
@@ -176,7 +183,7 @@ impl traits::Op for LinebreakBefore {
         } * lines_prio)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         let width: i64 = args.get(1)?.try_into()?;
 
@@ -2,8 +2,9 @@
 
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -70,13 +71,19 @@ impl LinesShortened {
     }
 }
 
-impl traits::Op for LinesShortened {
-    fn name() -> &'static str { "lines-shortened" }
-    fn usage() -> &'static str { "<#1 string text> <#2 int width>" }
-    fn description() -> &'static str { "shorten lines in string #1, if necessary, not to exceed width #2" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for LinesShortened {
+    fn name(&self) -> &'static str { "lines-shortened" }
+    fn usage(&self) -> &'static str { "<#1 string text> <#2 int width>" }
+    fn description(&self) -> &'static str { "shorten lines in string #1, if necessary, not to exceed width #2" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["abc", "5"], "abc\n".into()),
+            Example::new(&["abcdefgh\nab", "4"], "[…] cdef […]\nab\n".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         let w: Result<i64, LibError> = args.get(1)?.try_into();
 
@@ -104,7 +111,7 @@ impl traits::Op for LinesShortened {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         let w: Result<i64, LibError> = args.get(1)?.try_into();
 
@@ -1,7 +1,7 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -189,13 +189,13 @@ impl LoremIpsum {
     }
 }
 
-impl traits::Op for LoremIpsum {
-    fn name() -> &'static str { "lorem-ipsum" }
-    fn usage() -> &'static str { "<#1 int number-of-words>" }
-    fn description() -> &'static str { "generate (int #1) words of an Lorem Ipsum text" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for LoremIpsum {
+    fn name(&self) -> &'static str { "lorem-ipsum" }
+    fn usage(&self) -> &'static str { "<#1 int number-of-words>" }
+    fn description(&self) -> &'static str { "generate (int #1) words of an Lorem Ipsum text" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         Ok(match string.parse::<i64>() {
             Ok(count) => {
@@ -209,7 +209,7 @@ impl traits::Op for LoremIpsum {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let words: &str = args.get(0)?.try_into()?;
         match words.parse::<i64>() {
             Ok(count_words) => Ok(Self::generate(count_words).into()),
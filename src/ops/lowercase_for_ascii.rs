@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -13,13 +14,19 @@ impl LowercaseForAscii {
     }
 }
 
-impl traits::Op for LowercaseForAscii {
-    fn name() -> &'static str { "lowercase-for-ascii" }
-    fn usage() -> &'static str { "<#1 string to-convert>" }
-    fn description() -> &'static str { "get locale-independent/ASCII lowercase version of string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for LowercaseForAscii {
+    fn name(&self) -> &'static str { "lowercase-for-ascii" }
+    fn usage(&self) -> &'static str { "<#1 string to-convert>" }
+    fn description(&self) -> &'static str { "get locale-independent/ASCII lowercase version of string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["Hello World"], "hello world".into()),
+            Example::new(&["ABC-123"], "abc-123".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s == Self::function_for_chars(s) {
             0.38
@@ -28,7 +35,7 @@ impl traits::Op for LowercaseForAscii {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let a: &str = args.get(0)?.try_into()?;
         Ok(Self::function_for_chars(a).into())
     }
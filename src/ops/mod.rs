@@ -1,7 +1,9 @@
-use crate::Configuration;
+use std::sync::Arc;
+
+use crate::registry::Op;
 
 macro_rules! spec {
-    ($s:ident) => { ($s::name, $s::description, $s::usage, $s::acceptable_number_of_arguments, $s::priority, $s::run) };
+    ($s:ident) => { || -> Arc<dyn Op> { Arc::new($s {}) } };
 }
 
 /// operations
@@ -83,6 +85,7 @@ mod subscript;
 mod substring_byte_indices;
 mod superscript;
 mod utf8_bytes;
+mod utf8_decode;
 mod utf16_big_endian_bytes;
 mod utf16_little_endian_bytes;
 mod word_clusters;
@@ -138,7 +141,7 @@ pub(crate) use is_whitespace_agnostically_equal::IsWhitespaceAgnosticallyEqual;
 pub(crate) use join::Join;
 pub(crate) use length_minimum::LengthMinimum;
 pub(crate) use length_maximum::LengthMaximum;
-pub(crate) use levensthein_distance::LevenstheinDistance;
+pub(crate) use levensthein_distance::{LevenstheinDistance, levenshtein_d1stance};
 pub(crate) use linebreak_before::LinebreakBefore;
 pub(crate) use lines_shortened::LinesShortened;
 pub(crate) use lorem_ipsum::LoremIpsum;
@@ -174,6 +177,7 @@ pub(crate) use substring_byte_indices::SubstringByteIndices;
 pub(crate) use superscript::Superscript;
 pub(crate) use uppercase_for_ascii::UppercaseForAscii;
 pub(crate) use utf8_bytes::Utf8Bytes;
+pub(crate) use utf8_decode::Utf8Decode;
 pub(crate) use utf16_little_endian_bytes::Utf16LittleEndianBytes;
 pub(crate) use utf16_big_endian_bytes::Utf16BigEndianBytes;
 pub(crate) use word_clusters::WordClusters;
@@ -189,25 +193,8 @@ pub(crate) use digest_sha256::DigestSha256;
 #[cfg(feature = "digest")]
 pub(crate) use digest_sha3::DigestSha3256;
 
-
-
-/// operation index
-pub(crate) mod traits;
-use crate::errors;
-use crate::input;
-use crate::output;
-use crate::range;
-
-use self::traits::Op;
-
-type FnName = fn () -> &'static str;
-type FnDesc = fn () -> &'static str;
-type FnUse = fn () -> &'static str;
-type FnNum = fn () -> range::Range;
-type FnPriority = fn (args: &input::Args, conf: &Configuration) -> Result<f32, errors::LibError>;
-type Fn = fn (args: &input::Args, conf: &Configuration) -> Result<output::Output, errors::LibError>;
-
-pub(crate) const INDEX: &[(FnName, FnDesc, FnUse, FnNum, FnPriority, Fn)] = &[
+/// operation index, constructing each built-in operation
+pub(crate) const INDEX: &[fn () -> Arc<dyn Op>] = &[
     spec!(Base64Decode),
     spec!(Base64UrlSafeDecode),
     spec!(Base64Encode),
@@ -284,6 +271,7 @@ pub(crate) const INDEX: &[(FnName, FnDesc, FnUse, FnNum, FnPriority, Fn)] = &[
     spec!(EmojiByName),
     spec!(UppercaseForAscii),
     spec!(Utf8Bytes),
+    spec!(Utf8Decode),
     spec!(Utf16LittleEndianBytes),
     spec!(Utf16BigEndianBytes),
     spec!(WordClusters),
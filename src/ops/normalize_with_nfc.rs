@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -12,18 +13,24 @@ pub struct NormalizeWithNFC {}
 
 impl NormalizeWithNFC {
     fn function_for_chars(string: &str) -> String {
-        string.chars().nfd().to_string()
+        string.chars().nfc().to_string()
     }
 }
 
-impl traits::Op for NormalizeWithNFC {
-    fn name() -> &'static str { "normalize-with-nfc" }
-    fn usage() -> &'static str { "<#1 string to-normalize>" }
+impl Op for NormalizeWithNFC {
+    fn name(&self) -> &'static str { "normalize-with-nfc" }
+    fn usage(&self) -> &'static str { "<#1 string to-normalize>" }
     // TODO add examples to description
-    fn description() -> &'static str { "NFC-normalize Unicode string #1 which applies canonical decomposition followed by canonical composition (c.f. UAX #15)" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn description(&self) -> &'static str { "NFC-normalize Unicode string #1 which applies canonical decomposition followed by canonical composition (c.f. UAX #15)" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["e\u{301}"], "é".into()),
+            Example::new(&["abc"], "abc".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         Ok(if unicode_normalization::is_nfc(text) {
             0.208
@@ -32,8 +39,20 @@ impl traits::Op for NormalizeWithNFC {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         Ok(Self::function_for_chars(text).into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_with_nfc() {
+        assert_eq!(NormalizeWithNFC::function_for_chars("e\u{301}"), "\u{e9}");
+        assert_eq!(NormalizeWithNFC::function_for_chars("\u{e9}"), "\u{e9}");
+        assert_eq!(NormalizeWithNFC::function_for_chars("abc"), "abc");
+    }
+}
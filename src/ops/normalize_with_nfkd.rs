@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -16,14 +17,20 @@ impl NormalizeWithNFKD {
     }
 }
 
-impl traits::Op for NormalizeWithNFKD {
-    fn name() -> &'static str { "normalize-with-nfkd" }
-    fn usage() -> &'static str { "<#1 string to-normalize>" }
+impl Op for NormalizeWithNFKD {
+    fn name(&self) -> &'static str { "normalize-with-nfkd" }
+    fn usage(&self) -> &'static str { "<#1 string to-normalize>" }
     // TODO add examples to description
-    fn description() -> &'static str { "NFKD-normalize Unicode string #1 which applies compatibility decomposition followed by canonical composition (c.f. UAX #15)" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn description(&self) -> &'static str { "NFKD-normalize Unicode string #1 which applies compatibility decomposition followed by canonical composition (c.f. UAX #15)" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["ﬁ"], "fi".into()),
+            Example::new(&["\u{e9}"], "e\u{301}".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         Ok(if unicode_normalization::is_nfkd(text) {
             0.208
@@ -32,7 +39,7 @@ impl traits::Op for NormalizeWithNFKD {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         Ok(Self::function_for_chars(text).into())
     }
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::output::OutputValue;
 use crate::range;
@@ -10,13 +11,20 @@ use regex;
 
 pub struct RegexSearch {}
 
-impl traits::Op for RegexSearch {
-    fn name() -> &'static str { "regex-search" }
-    fn usage() -> &'static str { "<#1 string pattern> <#2 string to-match>" }
-    fn description() -> &'static str { "does regex pattern #1 occur anywhere inside #2? if so, return matching substring, otherwise empty string" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for RegexSearch {
+    fn name(&self) -> &'static str { "regex-search" }
+    fn usage(&self) -> &'static str { "<#1 regex pattern> <#2 string to-match>" }
+    fn description(&self) -> &'static str { "does regex pattern #1 occur anywhere inside #2? if so, return matching substring, otherwise empty string" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn feature(&self) -> Option<&'static str> { Some("regex") }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["[0-9]+", "abc 123 def"], "123".into()),
+            Example::new(&["x", "abc"], "".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let pattern: &str = args.get(0)?.try_into()?;
         let mut prio = 0.6;
 
@@ -39,12 +47,12 @@ impl traits::Op for RegexSearch {
         Ok(prio)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let pattern: &str = args.get(0)?.try_into()?;
         let haystack: &str = args.get(1)?.try_into()?;
         let re = match regex::Regex::new(pattern) {
             Ok(re) => re,
-            Err(_) => return Err(LibError::ArgValueError(0, format!("the string '{}' is not a valid regular expression for rust's regex library", pattern))),
+            Err(_) => return Err(LibError::OpError(0, "invalid-regex", format!("the string '{}' is not a valid regular expression for rust's regex library", pattern))),
         };
 
         let mut result = OutputValue::from_str("");
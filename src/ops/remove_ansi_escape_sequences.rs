@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -245,13 +246,19 @@ impl RemoveAnsiEscapeSequences {
     }
 }
 
-impl traits::Op for RemoveAnsiEscapeSequences {
-    fn name() -> &'static str { "remove-ansi-escape-sequences" }
-    fn usage() -> &'static str { "<#1 string to-simplify>" }
-    fn description() -> &'static str { "remove any ANSI X3.64 (also found in ECMA-48/ISO 6429) sequences in string #1 starting with U+001B ESCAPE" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for RemoveAnsiEscapeSequences {
+    fn name(&self) -> &'static str { "remove-ansi-escape-sequences" }
+    fn usage(&self) -> &'static str { "<#1 string to-simplify>" }
+    fn description(&self) -> &'static str { "remove any ANSI X3.64 (also found in ECMA-48/ISO 6429) sequences in string #1 starting with U+001B ESCAPE" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["\u{1b}[1mbold\u{1b}[0m"], "bold".into()),
+            Example::new(&["plain"], "plain".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         if s.contains(ESC) {
             Ok(0.493)
@@ -260,7 +267,7 @@ impl traits::Op for RemoveAnsiEscapeSequences {
         }
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let src: &str = args.get(0)?.try_into()?;
         let dst = Self::function_for_chars(src);
 
@@ -1,8 +1,10 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
+use crate::params::{Param, ParamType};
 use crate::range;
 
 pub struct Repeat {}
@@ -10,15 +12,15 @@ pub struct Repeat {}
 impl Repeat {
     fn implementation(arg: &str, count: i64, count_arg_id: usize) -> Result<Output, LibError> {
         if count < 0 {
-            return Err(LibError::ArgValueError(count_arg_id, format!("cannot repeat {} (negative) times - nonnegative integer required", count)));
+            return Err(LibError::OpError(count_arg_id, "negative-count", format!("cannot repeat {} (negative) times - nonnegative integer required", count)));
         }
         if count > u16::MAX as i64 {
-            return Err(LibError::ArgValueError(count_arg_id, "count argument is too large".to_owned()));
+            return Err(LibError::OpError(count_arg_id, "count-too-large", "count argument is too large".to_owned()));
         }
 
         let limit = 65_535i64.saturating_div(arg.chars().count() as i64);
         if count > limit {
-            return Err(LibError::ArgValueError(count_arg_id, format!("repeated string exceeds length limit {}", limit)))
+            return Err(LibError::OpError(count_arg_id, "result-too-long", format!("repeated string exceeds length limit {}", limit)))
         }
 
         Ok(arg.repeat(count as usize).into())
@@ -42,13 +44,21 @@ impl Repeat {
     }
 }
 
-impl traits::Op for Repeat {
-    fn name() -> &'static str { "repeat" }
-    fn usage() -> &'static str { "<#1 string to-repeat> <#2 int repetitions>" }
-    fn description() -> &'static str { "repeat string #1 several (integer #2) times" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for Repeat {
+    fn name(&self) -> &'static str { "repeat" }
+    fn usage(&self) -> &'static str { "<#1 string to-repeat> <#2 int repetitions>" }
+    // NOTE: the order of arguments is accepted leniently, so the integer is validated in `run`
+    fn params(&self) -> Vec<Param> { vec![Param::new("to-repeat", ParamType::String), Param::new("repetitions", ParamType::String)] }
+    fn description(&self) -> &'static str { "repeat string #1 several (integer #2) times" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["ab", "3"], "ababab".into()),
+            Example::new(&["3", "ab"], "ababab".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let argument1: &str = args.get(0)?.try_into()?;
         let argument2: &str = args.get(1)?.try_into()?;
 
@@ -63,7 +73,7 @@ impl traits::Op for Repeat {
         Ok(0.0)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let argument1: &str = args.get(0)?.try_into()?;
         let argument2: &str = args.get(1)?.try_into()?;
 
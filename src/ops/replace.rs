@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -13,13 +14,19 @@ impl Replace {
     }
 }
 
-impl traits::Op for Replace {
-    fn name() -> &'static str { "replace" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string to-search> <#3 string replacement>" }
-    fn description() -> &'static str { "replace string #2 with string #3 in string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(3, 3) }
+impl Op for Replace {
+    fn name(&self) -> &'static str { "replace" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string to-search> <#3 string replacement>" }
+    fn description(&self) -> &'static str { "replace string #2 with string #3 in string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(3, 3) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello world", "o", "0"], "hell0 w0rld".into()),
+            Example::new(&["aaa", "a", "bb"], "bbbbbb".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let base_arg: &str = args.get(0)?.try_into()?;
         let search_arg: &str = args.get(1)?.try_into()?;
 
@@ -39,7 +46,7 @@ impl traits::Op for Replace {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
         let s3: &str = args.get(2)?.try_into()?;
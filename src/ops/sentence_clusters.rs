@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
@@ -9,18 +10,24 @@ use unicode_segmentation::UnicodeSegmentation;
 
 pub struct SentenceClusters {}
 
-impl traits::Op for SentenceClusters {
-    fn name() -> &'static str { "sentence-clusters" }
-    fn usage() -> &'static str { "<#1 string to-analyze>" }
-    fn description() -> &'static str { "return “Sentence clusters” according to Unicode Standard Annex #29 “Unicode Text Segmentation”" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for SentenceClusters {
+    fn name(&self) -> &'static str { "sentence-clusters" }
+    fn usage(&self) -> &'static str { "<#1 string to-analyze>" }
+    fn description(&self) -> &'static str { "return “Sentence clusters” according to Unicode Standard Annex #29 “Unicode Text Segmentation”" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["Hello. How are you?"], Output::HomogeneousList { data: vec![OutputValue::from_str("Hello"), OutputValue::from_str("."), OutputValue::from_str(" "), OutputValue::from_str("How"), OutputValue::from_str(" "), OutputValue::from_str("are"), OutputValue::from_str(" "), OutputValue::from_str("you"), OutputValue::from_str("?")], notes: vec![] }),
+            Example::new(&["One sentence"], Output::HomogeneousList { data: vec![OutputValue::from_str("One"), OutputValue::from_str(" "), OutputValue::from_str("sentence")], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         Ok(if string.len() >= 20 { 0.59 } else { 0.23 })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let data = string.split_word_bounds().map(OutputValue::from_str).collect::<Vec<OutputValue>>();
         Ok(Output::HomogeneousList { data, notes: vec![] })
@@ -1,20 +1,27 @@
 use crate::auxiliary;
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct Similarity {}
 
-impl traits::Op for Similarity {
-    fn name() -> &'static str { "similarity" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string comparison>" }
-    fn description() -> &'static str { "indicate similarity (0 = not, 100 = equal) of two strings with a number between 0 and 100" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for Similarity {
+    fn name(&self) -> &'static str { "similarity" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string comparison>" }
+    fn description(&self) -> &'static str { "indicate similarity (0 = not, 100 = equal) of two strings with a number between 0 and 100" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hello", "hello"], 100usize.into()),
+            Example::new(&["abcd", "abce"], 87usize.into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s1 = args.get(0)?.try_into()?;
         let s2 = args.get(1)?.try_into()?;
         let sim = auxiliary::string_similarity(s1, s2);
@@ -27,7 +34,7 @@ impl traits::Op for Similarity {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s1 = args.get(0)?.try_into()?;
         let s2 = args.get(1)?.try_into()?;
         let sim = auxiliary::string_similarity(s1, s2);
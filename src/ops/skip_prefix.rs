@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct SkipPrefix {}
 
-impl traits::Op for SkipPrefix {
-    fn name() -> &'static str { "skip-prefix" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string prefix>" }
-    fn description() -> &'static str { "remove string #2 from the beginning of string #1 if it exists" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for SkipPrefix {
+    fn name(&self) -> &'static str { "skip-prefix" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string prefix>" }
+    fn description(&self) -> &'static str { "remove string #2 from the beginning of string #1 if it exists" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["prefix-name", "prefix-"], "name".into()),
+            Example::new(&["name", "prefix-"], "name".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
         Ok(if s1.starts_with(s2) {
@@ -23,7 +30,7 @@ impl traits::Op for SkipPrefix {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
         if s1.starts_with(s2) {
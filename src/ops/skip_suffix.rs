@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct SkipSuffix {}
 
-impl traits::Op for SkipSuffix {
-    fn name() -> &'static str { "skip-suffix" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string suffix>" }
-    fn description() -> &'static str { "remove string #2 from the end of string #1 if it exists" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for SkipSuffix {
+    fn name(&self) -> &'static str { "skip-suffix" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string suffix>" }
+    fn description(&self) -> &'static str { "remove string #2 from the end of string #1 if it exists" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["file.txt", ".txt"], "file".into()),
+            Example::new(&["file", ".txt"], "file".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
         Ok(if s1.ends_with(s2) {
@@ -23,7 +30,7 @@ impl traits::Op for SkipSuffix {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s1: &str = args.get(0)?.try_into()?;
         let s2: &str = args.get(1)?.try_into()?;
         if s1.ends_with(s2) {
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
@@ -10,8 +11,10 @@ pub struct Sort {}
 impl Sort {
     #[cfg(feature = "icu")]
     fn sort_strings(conf: &Configuration, notes: &mut Vec<String>, mut strings: Vec<String>) -> Result<Vec<String>, LibError> {
-        use std::env;
+        use std::cell::RefCell;
+        use std::collections::HashMap;
         use std::fs;
+        use std::rc::Rc;
 
         // TODO reduce number of imports
         use icu::collator::*;
@@ -19,28 +22,48 @@ impl Sort {
         use icu_provider_blob::BlobDataProvider;
         use icu_provider_adapters::fallback::LocaleFallbackProvider;
 
+        /// Collators per (locale, locale data file template)
+        type CollatorCache = RefCell<HashMap<(String, Option<String>), Rc<Collator>>>;
+
+        thread_local! {
+            // locale data is loaded only once per thread
+            static COLLATORS: CollatorCache = RefCell::new(HashMap::new());
+        }
+
         // prepare collation
         if let Some(loc) = &conf.locale {
-            // read locale data file or use default data contained in binary
-            let blob_provider = if let Ok(filepath_template) = env::var("OPSTR_LOCALE_DATAFILE") {
-                let filepath = filepath_template.replace("{locale}", &loc.to_string());
-                notes.push(format!("Using locale data from '{}' to initialize collator to sort strings", filepath));
-
-                let errmsg = format!("file '{}' mentioned in environment variable OPSTR_LOCALE_DATAFILE cannot be read", filepath);
-                let data = fs::read(&filepath).expect(&errmsg);
-
-                BlobDataProvider::try_new_from_blob(data.into_boxed_slice())?
-            } else {
-                notes.push(format!("Using default locale data shipped with this program to initialize collator to sort strings"));
-                BlobDataProvider::try_new_from_static_blob(crate::DEFAULT_LOCALE_DATA)?
-            };
-            let buffer_provider = LocaleFallbackProvider::try_new_with_buffer_provider(blob_provider)?;
+            let filepath = conf.locale_datafile.as_ref().map(|template| template.replace("{locale}", &loc.to_string()));
+            match &filepath {
+                Some(path) => notes.push(format!("Using locale data from '{}' to initialize collator to sort strings", path)),
+                None => notes.push("Using default locale data shipped with this program to initialize collator to sort strings".to_owned()),
+            }
 
-            // create collator to sort strings
-            let data_locale = DataLocale::from(loc);
-            let mut options = CollatorOptions::new();
-            options.strength = Some(Strength::Primary);
-            let collator: Collator = Collator::try_new_with_buffer_provider(&buffer_provider, &data_locale, options)?;
+            let key = (loc.to_string(), conf.locale_datafile.clone());
+            let collator = match COLLATORS.with(|cache| cache.borrow().get(&key).cloned()) {
+                Some(collator) => collator,
+                None => {
+                    // read locale data file or use default data contained in binary
+                    let blob_provider = if let Some(filepath) = &filepath {
+                        let data = match fs::read(filepath) {
+                            Ok(d) => d,
+                            Err(e) => return Err(LibError::LocaleError(format!("configured locale data file '{}' cannot be read: {}", filepath, e))),
+                        };
+                        BlobDataProvider::try_new_from_blob(data.into_boxed_slice())?
+                    } else {
+                        BlobDataProvider::try_new_from_static_blob(crate::DEFAULT_LOCALE_DATA)?
+                    };
+                    let buffer_provider = LocaleFallbackProvider::try_new_with_buffer_provider(blob_provider)?;
+
+                    // create collator to sort strings
+                    let data_locale = DataLocale::from(loc);
+                    let mut options = CollatorOptions::new();
+                    options.strength = Some(Strength::Primary);
+                    let collator = Rc::new(Collator::try_new_with_buffer_provider(&buffer_provider, &data_locale, options)?);
+
+                    COLLATORS.with(|cache| cache.borrow_mut().insert(key, Rc::clone(&collator)));
+                    collator
+                },
+            };
 
             strings.sort_by(|a, b| collator.compare(a, b));
         } else {
@@ -63,20 +86,28 @@ impl Sort {
     }
 }
 
-impl traits::Op for Sort {
-    fn name() -> &'static str { "sort" }
-    fn usage() -> &'static str { "[<#1 string to-sort> one or more times]" }
-    fn description() -> &'static str { "sort the strings provided" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(1) }
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.5) }
+impl Op for Sort {
+    fn name(&self) -> &'static str { "sort" }
+    fn usage(&self) -> &'static str { "[<#1 string to-sort> one or more times]" }
+    fn description(&self) -> &'static str { "sort the strings provided" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(1) }
+    fn locale_sensitive(&self) -> bool { true }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["b", "c", "a"], Output::HomogeneousList { data: vec![OutputValue::from_str("a"), OutputValue::from_str("b"), OutputValue::from_str("c")], notes: vec![] }),
+            Example::new(&["banana", "apple"], Output::HomogeneousList { data: vec![OutputValue::from_str("apple"), OutputValue::from_str("banana")], notes: vec![] }),
+        ]
+    }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.5) }
 
-    fn run(args: &Args, conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, conf: &Configuration) -> Result<Output, LibError> {
         let mut notes = vec![];
 
         // fetch arguments as strings
         let mut strings: Vec<String> = vec![];
         for arg in args.iter() {
-            strings.push(arg.str_or_panic().to_string());
+            let s: &str = arg.try_into()?;
+            strings.push(s.to_string());
         }
 
         strings = Self::sort_strings(conf, &mut notes, strings)?;
@@ -1,24 +1,32 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
 pub struct SortLexicographically {}
 
-impl traits::Op for SortLexicographically {
-    fn name() -> &'static str { "sort-lexicographically" }
-    fn usage() -> &'static str { "[<#1 string to-sort> one or more times]" }
-    fn description() -> &'static str { "sort the strings provided lexicographically by their Unicode codepoints" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(1) }
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.5) }
+impl Op for SortLexicographically {
+    fn name(&self) -> &'static str { "sort-lexicographically" }
+    fn usage(&self) -> &'static str { "[<#1 string to-sort> one or more times]" }
+    fn description(&self) -> &'static str { "sort the strings provided lexicographically by their Unicode codepoints" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["b", "C", "a"], Output::HomogeneousList { data: vec![OutputValue::from_str("C"), OutputValue::from_str("a"), OutputValue::from_str("b")], notes: vec![] }),
+            Example::new(&["ä", "z"], Output::HomogeneousList { data: vec![OutputValue::from_str("z"), OutputValue::from_str("ä")], notes: vec![] }),
+        ]
+    }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.5) }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         // fetch arguments as strings
         let mut strings: Vec<String> = vec![];
         for arg in args.iter() {
-            strings.push(arg.str_or_panic().to_string());
+            let s: &str = arg.try_into()?;
+            strings.push(s.to_string());
         }
 
         // Sort strings lexicographically.
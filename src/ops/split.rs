@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
 pub struct Split {}
 
-impl traits::Op for Split {
-    fn name() -> &'static str { "split" }
-    fn usage() -> &'static str { "<#1 string to-split> [<#2 string separator> one or more times]" }
-    fn description() -> &'static str { "split string #1 by any of the provided substrings #2, or #3, or …" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexOpen(2) }
+impl Op for Split {
+    fn name(&self) -> &'static str { "split" }
+    fn usage(&self) -> &'static str { "<#1 string to-split> [<#2 string separator> one or more times]" }
+    fn description(&self) -> &'static str { "split string #1 by any of the provided substrings #2, or #3, or …" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexOpen(2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a,b;c", ",", ";"], Output::HomogeneousList { data: vec![OutputValue::from_str("a"), OutputValue::from_str("b"), OutputValue::from_str("c")], notes: vec![] }),
+            Example::new(&["a b", " "], Output::HomogeneousList { data: vec![OutputValue::from_str("a"), OutputValue::from_str("b")], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         Ok(if args.len() < 2 {
             0.0
         } else if 2 <= args.len() && args.len() <= 5 {
@@ -23,7 +30,7 @@ impl traits::Op for Split {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
 
         let mut seps = vec![];
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
 pub struct SplitByWhitespaces {}
 
-impl traits::Op for SplitByWhitespaces {
-    fn name() -> &'static str { "split-by-whitespaces" }
-    fn usage() -> &'static str { "<#1 string to-split>" }
-    fn description() -> &'static str { "split string #1 by any character of Unicode category Whitespace" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for SplitByWhitespaces {
+    fn name(&self) -> &'static str { "split-by-whitespaces" }
+    fn usage(&self) -> &'static str { "<#1 string to-split>" }
+    fn description(&self) -> &'static str { "split string #1 by any character of Unicode category Whitespace" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a b\tc"], Output::HomogeneousList { data: vec![OutputValue::from_str("a"), OutputValue::from_str("b"), OutputValue::from_str("c")], notes: vec![] }),
+            Example::new(&[" x  y "], Output::HomogeneousList { data: vec![OutputValue::from_str("x"), OutputValue::from_str("y")], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let length = string.chars().count();
         let ws_count = string.split_whitespace().count();
@@ -21,7 +28,7 @@ impl traits::Op for SplitByWhitespaces {
         Ok(ws_count as f32 / length as f32)
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let result = string.split_whitespace();
         Ok(Output::HomogeneousList {
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
 pub struct SplitByWhitespacesLimitedAtEnd {}
 
-impl traits::Op for SplitByWhitespacesLimitedAtEnd {
-    fn name() -> &'static str { "split-by-whitespaces-limited-at-end" }
-    fn usage() -> &'static str { "<#1 string to-split> <#2 int times>" }
-    fn description() -> &'static str { "split at most #2 times from the end of the string #1 by any character of Unicode category Whitespace" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for SplitByWhitespacesLimitedAtEnd {
+    fn name(&self) -> &'static str { "split-by-whitespaces-limited-at-end" }
+    fn usage(&self) -> &'static str { "<#1 string to-split> <#2 int times>" }
+    fn description(&self) -> &'static str { "split at most #2 times from the end of the string #1 by any character of Unicode category Whitespace" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a b c d", "1"], Output::HomogeneousList { data: vec![OutputValue::from_str("a b c"), OutputValue::from_str("d")], notes: vec![] }),
+            Example::new(&["a b c d", "2"], Output::HomogeneousList { data: vec![OutputValue::from_str("a b"), OutputValue::from_str("c"), OutputValue::from_str("d")], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let limit: Result<i64, LibError> = args.get(1)?.try_into();
 
         Ok(match limit {
@@ -22,7 +29,7 @@ impl traits::Op for SplitByWhitespacesLimitedAtEnd {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let limit: Result<i64, LibError> = args.get(1)?.try_into();
 
@@ -33,21 +40,16 @@ impl traits::Op for SplitByWhitespacesLimitedAtEnd {
                 let mut end = string.len();
 
                 for _ in 0..i as usize {
-                    match string.rfind(char::is_whitespace) {
+                    match string[..end].rfind(char::is_whitespace) {
                         Some(byte_index) => {
-                            parts.push(&string[byte_index..end]);
+                            let whitespace_len = string[byte_index..].chars().next().map_or(1, char::len_utf8);
+                            parts.push(&string[byte_index + whitespace_len..end]);
                             end = byte_index;
                         },
-                        None => {
-                            parts.push(string);
-                            end = 0;
-                            break;
-                        },
+                        None => break,
                     }
                 }
-                if parts.len() == i as usize && end != 0 {
-                    parts.push(&string[..end]);
-                }
+                parts.push(&string[..end]);
                 parts.reverse();
 
                 let list = parts.iter().map(|s| OutputValue::from_str(s)).collect::<Vec<OutputValue>>();
@@ -57,3 +59,27 @@ impl traits::Op for SplitByWhitespacesLimitedAtEnd {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Arg;
+    use crate::registry::Op;
+
+    fn split(s: &str, times: &str) -> Output {
+        let args = Args::from(&[Arg::from_str(s, 0), Arg::from_str(times, 1)]);
+        SplitByWhitespacesLimitedAtEnd {}.run(&args, &Configuration::default()).unwrap()
+    }
+
+    fn list(items: &[&str]) -> Output {
+        Output::HomogeneousList { data: items.iter().map(|s| OutputValue::from_str(s)).collect(), notes: vec![] }
+    }
+
+    #[test]
+    fn test_split_limited_at_end() {
+        assert_eq!(split("a b c d", "0"), list(&["a b c d"]));
+        assert_eq!(split("a b c d", "1"), list(&["a b c", "d"]));
+        assert_eq!(split("a b c d", "2"), list(&["a b", "c", "d"]));
+        assert_eq!(split("a b", "5"), list(&["a", "b"]));
+    }
+}
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
 pub struct SplitByWhitespacesLimitedAtStart {}
 
-impl traits::Op for SplitByWhitespacesLimitedAtStart {
-    fn name() -> &'static str { "split-by-whitespaces-limited-at-start" }
-    fn usage() -> &'static str { "<#1 string to-split> <#2 int times>" }
-    fn description() -> &'static str { "split at most #2 times at the start of the string #1 by any character of Unicode category Whitespace" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for SplitByWhitespacesLimitedAtStart {
+    fn name(&self) -> &'static str { "split-by-whitespaces-limited-at-start" }
+    fn usage(&self) -> &'static str { "<#1 string to-split> <#2 int times>" }
+    fn description(&self) -> &'static str { "split at most #2 times at the start of the string #1 by any character of Unicode category Whitespace" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["a b c d", "2"], Output::HomogeneousList { data: vec![OutputValue::from_str("a"), OutputValue::from_str("b c d")], notes: vec![] }),
+            Example::new(&["a b c d", "3"], Output::HomogeneousList { data: vec![OutputValue::from_str("a"), OutputValue::from_str("b"), OutputValue::from_str("c d")], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let limit: Result<i64, LibError> = args.get(1)?.try_into();
 
         Ok(match limit {
@@ -22,7 +29,7 @@ impl traits::Op for SplitByWhitespacesLimitedAtStart {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let limit: Result<i64, LibError> = args.get(1)?.try_into();
 
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -11,14 +12,20 @@ const COMBINING_LONG_STROKE_OVERLAY: char = '\u{0336}';
 
 pub struct StrikeThrough {}
 
-impl traits::Op for StrikeThrough {
-    fn name() -> &'static str { "strike-through" }
-    fn usage() -> &'static str { "<#1 string text>" }
-    fn description() -> &'static str { "add U+0336 COMBINING LONG STROKE OVERLAY before each codepoint resulting in strike-through text" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
-    fn priority(_args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.382) }
+impl Op for StrikeThrough {
+    fn name(&self) -> &'static str { "strike-through" }
+    fn usage(&self) -> &'static str { "<#1 string text>" }
+    fn description(&self) -> &'static str { "add U+0336 COMBINING LONG STROKE OVERLAY before each codepoint resulting in strike-through text" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["ab"], "a\u{336}b\u{336}".into()),
+            Example::new(&["x"], "x\u{336}".into()),
+        ]
+    }
+    fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.382) }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         let mut result = String::new();
         for grapheme in UnicodeSegmentation::graphemes(s, true) {
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct StripCodepoints {}
 
-impl traits::Op for StripCodepoints {
-    fn name() -> &'static str { "strip-codepoints" }
-    fn usage() -> &'static str { "<#1 string text> <#2 string codepoints>" }
-    fn description() -> &'static str { "strip codepoints found in string #2 from start or end of string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for StripCodepoints {
+    fn name(&self) -> &'static str { "strip-codepoints" }
+    fn usage(&self) -> &'static str { "<#1 string text> <#2 string codepoints>" }
+    fn description(&self) -> &'static str { "strip codepoints found in string #2 from start or end of string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["--x--", "-"], "x".into()),
+            Example::new(&["xyzabczyx", "xyz"], "abc".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let set: &str = args.get(1)?.try_into()?;
         Ok(if !set.is_empty() && set.len() <= 3 {
             0.493
@@ -22,7 +29,7 @@ impl traits::Op for StripCodepoints {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let string: &str = args.get(0)?.try_into()?;
         let set: &str = args.get(1)?.try_into()?;
 
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -13,13 +14,19 @@ impl StripWhitespacesAtEnd {
     }
 }
 
-impl traits::Op for StripWhitespacesAtEnd {
-    fn name() -> &'static str { "strip-whitespaces-at-end" }
-    fn usage() -> &'static str { "<#1 string text>" }
-    fn description() -> &'static str { "strip whitespaces from end of string" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for StripWhitespacesAtEnd {
+    fn name(&self) -> &'static str { "strip-whitespaces-at-end" }
+    fn usage(&self) -> &'static str { "<#1 string text>" }
+    fn description(&self) -> &'static str { "strip whitespaces from end of string" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["  hello  "], "  hello".into()),
+            Example::new(&["x\n"], "x".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s == s.trim_end() {
             0.24
@@ -28,7 +35,7 @@ impl traits::Op for StripWhitespacesAtEnd {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let a: &str = args.get(0)?.try_into()?;
         Ok(Self::function_for_chars(a).into())
     }
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -13,13 +14,19 @@ impl StripWhitespacesAtStart {
     }
 }
 
-impl traits::Op for StripWhitespacesAtStart {
-    fn name() -> &'static str { "strip-whitespaces-at-start" }
-    fn usage() -> &'static str { "<#1 string text>" }
-    fn description() -> &'static str { "strip whitespaces from start of string" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for StripWhitespacesAtStart {
+    fn name(&self) -> &'static str { "strip-whitespaces-at-start" }
+    fn usage(&self) -> &'static str { "<#1 string text>" }
+    fn description(&self) -> &'static str { "strip whitespaces from start of string" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["  hello  "], "hello  ".into()),
+            Example::new(&["\tx"], "x".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s == s.trim_start() {
             0.24
@@ -28,7 +35,7 @@ impl traits::Op for StripWhitespacesAtStart {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(Self::function_for_chars(s).into())
     }
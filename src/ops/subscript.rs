@@ -1,8 +1,9 @@
 use crate::auxiliary;
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -89,13 +90,19 @@ impl Subscript {
     }
 }
 
-impl traits::Op for Subscript {
-    fn name() -> &'static str { "subscript" }
-    fn usage() -> &'static str { "<#1 string to-convert>" }
-    fn description() -> &'static str { "return the subscript version of the provided string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Subscript {
+    fn name(&self) -> &'static str { "subscript" }
+    fn usage(&self) -> &'static str { "<#1 string to-convert>" }
+    fn description(&self) -> &'static str { "return the subscript version of the provided string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["H2O"], "H₂O".into()),
+            Example::new(&["123"], "₁₂₃".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         let sub: &str = &Self::apply_replacements(text);
         let diff = auxiliary::count_different_codepoints_of_shorter_string(text, sub);
@@ -107,7 +114,7 @@ impl traits::Op for Subscript {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         Ok(Self::apply_replacements(text).into())
     }
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output, OutputValue};
 use crate::range;
 
 pub struct SubstringByteIndices {}
 
-impl traits::Op for SubstringByteIndices {
-    fn name() -> &'static str { "substring-byte-indices" }
-    fn usage() -> &'static str { "<#1 string base> <#2 string search>" }
-    fn description() -> &'static str { "return the byte indices where string #2 can be found in string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(2, 2) }
+impl Op for SubstringByteIndices {
+    fn name(&self) -> &'static str { "substring-byte-indices" }
+    fn usage(&self) -> &'static str { "<#1 string base> <#2 string search>" }
+    fn description(&self) -> &'static str { "return the byte indices where string #2 can be found in string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(2, 2) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["abcabc", "bc"], Output::HeterogeneousList { data: vec![OutputValue::Int(1), OutputValue::Int(4)], notes: vec![] }),
+            Example::new(&["äbä", "ä"], Output::HeterogeneousList { data: vec![OutputValue::Int(0), OutputValue::Int(3)], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let base: &str = args.get(0)?.try_into()?;
         let search: &str = args.get(1)?.try_into()?;
 
@@ -24,26 +31,37 @@ impl traits::Op for SubstringByteIndices {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let base: &str = args.get(0)?.try_into()?;
         let search: &str = args.get(1)?.try_into()?;
 
-        let string_bytes = base.as_bytes();
-
-        let mut data = vec![];
-        let byte_offset = 0;
-        
-        loop {
-            let base = byte_offset + search.len();
-            //let opt_idx = (&string_bytes[base..]).match_indices(search.as_bytes());
-            let opt_idx = string_bytes[base..].windows(search.as_bytes().len()).position(|window| window == search.as_bytes());
-            if let Some(idx) = opt_idx {
-                data.push(OutputValue::Int((base as i64) + (idx as i64)));
-            } else {
-                break;
-            }
-        }
+        let data = base.match_indices(search)
+            .map(|(idx, _)| OutputValue::Int(idx as i64))
+            .collect::<Vec<OutputValue>>();
 
         Ok(Output::HeterogeneousList { data, notes: vec![] })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Arg;
+    use crate::registry::Op;
+
+    #[test]
+    fn test_substring_byte_indices() {
+        let indices = |base: &str, search: &str| {
+            let args = Args::from(&[Arg::from_str(base, 0), Arg::from_str(search, 1)]);
+            SubstringByteIndices {}.run(&args, &Configuration::default()).unwrap()
+        };
+        let list = |data: &[i64]| Output::HeterogeneousList { data: data.iter().map(|i| OutputValue::Int(*i)).collect(), notes: vec![] };
+
+        assert_eq!(indices("abxx", "ab"), list(&[0]));
+        assert_eq!(indices("abcabc", "bc"), list(&[1, 4]));
+        assert_eq!(indices("äbä", "ä"), list(&[0, 3]));
+        // matches do not overlap
+        assert_eq!(indices("aaa", "aa"), list(&[0]));
+        assert_eq!(indices("abc", "x"), list(&[]));
+    }
+}
@@ -1,8 +1,9 @@
 use crate::auxiliary;
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
@@ -333,13 +334,19 @@ impl Superscript {
     }
 }
 
-impl traits::Op for Superscript {
-    fn name() -> &'static str { "superscript" }
-    fn usage() -> &'static str { "<#1 string to-convert>" }
-    fn description() -> &'static str { "return the superscript version of the provided string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Superscript {
+    fn name(&self) -> &'static str { "superscript" }
+    fn usage(&self) -> &'static str { "<#1 string to-convert>" }
+    fn description(&self) -> &'static str { "return the superscript version of the provided string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["x2"], "ˣ²".into()),
+            Example::new(&["123"], "¹²³".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         let sub: &str = &Self::apply_replacements(text);
         let diff = auxiliary::count_different_codepoints_of_shorter_string(text, sub);
@@ -351,7 +358,7 @@ impl traits::Op for Superscript {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let text: &str = args.get(0)?.try_into()?;
         Ok(Self::apply_replacements(text).into())
     }
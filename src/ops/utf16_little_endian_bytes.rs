@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output,OutputValue};
 use crate::range;
 
 pub struct Utf16LittleEndianBytes {}
 
-impl traits::Op for Utf16LittleEndianBytes {
-    fn name() -> &'static str { "utf16-little-endian-bytes" }
-    fn usage() -> &'static str { "<#1 string to-encode>" }
-    fn description() -> &'static str { "encode string #1 in UTF-16 and return its bytes in little endian order" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Utf16LittleEndianBytes {
+    fn name(&self) -> &'static str { "utf16-little-endian-bytes" }
+    fn usage(&self) -> &'static str { "<#1 string to-encode>" }
+    fn description(&self) -> &'static str { "encode string #1 in UTF-16 and return its bytes in little endian order" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hi"], Output::HomogeneousList { data: vec![OutputValue::Byte(104), OutputValue::Byte(0), OutputValue::Byte(105), OutputValue::Byte(0)], notes: vec![] }),
+            Example::new(&["€"], Output::HomogeneousList { data: vec![OutputValue::Byte(172), OutputValue::Byte(32)], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s.len() > 3 {
             0.86
@@ -22,7 +29,7 @@ impl traits::Op for Utf16LittleEndianBytes {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         let mut list = vec![];
         for two_bytes in s.encode_utf16() {
@@ -1,7 +1,8 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::{Output,OutputValue};
 use crate::range;
 
@@ -9,13 +10,19 @@ use std::vec;
 
 pub struct Utf8Bytes {}
 
-impl traits::Op for Utf8Bytes {
-    fn name() -> &'static str { "utf8-bytes" }
-    fn usage() -> &'static str { "<#1 string to-encode>" }
-    fn description() -> &'static str { "encode string #1 in UTF-8 and return its bytes" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for Utf8Bytes {
+    fn name(&self) -> &'static str { "utf8-bytes" }
+    fn usage(&self) -> &'static str { "<#1 string to-encode>" }
+    fn description(&self) -> &'static str { "encode string #1 in UTF-8 and return its bytes" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["hi"], Output::HomogeneousList { data: vec![OutputValue::Byte(104), OutputValue::Byte(105)], notes: vec![] }),
+            Example::new(&["€"], Output::HomogeneousList { data: vec![OutputValue::Byte(226), OutputValue::Byte(130), OutputValue::Byte(172)], notes: vec![] }),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s.len() > 3 {
             0.86
@@ -24,10 +31,10 @@ impl traits::Op for Utf8Bytes {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
-        let s: &str = args.get(0)?.try_into()?;
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        let bytes: &[u8] = args.get(0)?.try_into()?;
         let list = Output::HomogeneousList {
-            data: s.bytes().map(|e| { OutputValue::Byte(e) }).collect::<Vec<OutputValue>>(),
+            data: bytes.iter().map(|e| { OutputValue::Byte(*e) }).collect::<Vec<OutputValue>>(),
             notes: vec![],
         };
         Ok(list)
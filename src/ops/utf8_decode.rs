@@ -0,0 +1,34 @@
+use crate::config::Configuration;
+use crate::errors::LibError;
+use crate::input::{Arg, Args};
+use crate::registry::Op;
+use crate::output::Output;
+use crate::range;
+
+use std::str;
+
+pub struct Utf8Decode {}
+
+impl Op for Utf8Decode {
+    fn name(&self) -> &'static str { "utf8-decode" }
+    fn usage(&self) -> &'static str { "<#1 bytes to-decode>" }
+    fn description(&self) -> &'static str { "decode bytes #1 as UTF-8 and return the string" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+        Ok(match args.get(0)? {
+            Arg::Chars(_, _) => 0.0,
+            Arg::Bytes(b, _) => if str::from_utf8(b).is_ok() { 0.91 } else { 0.0 },
+        })
+    }
+
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+        match args.get(0)? {
+            Arg::Chars(_, idx) => Err(LibError::ArgTypeError(*idx, "argument is already a string, bytes expected".to_owned())),
+            Arg::Bytes(b, idx) => match str::from_utf8(b) {
+                Ok(s) => Ok(s.into()),
+                Err(e) => Err(LibError::OpError(*idx, "invalid-utf8", format!("invalid UTF-8 sequence at byte offset {}", e.valid_up_to()))),
+            },
+        }
+    }
+}
@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct XmlDecode {}
 
-impl traits::Op for XmlDecode {
-    fn name() -> &'static str { "xml-decode" }
-    fn usage() -> &'static str { "<#1 string to-decode>" }
-    fn description() -> &'static str { "replace the 5 pre-defined XML entities with their unescaped characters &<>\"' in string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for XmlDecode {
+    fn name(&self) -> &'static str { "xml-decode" }
+    fn usage(&self) -> &'static str { "<#1 string to-decode>" }
+    fn description(&self) -> &'static str { "replace the 5 pre-defined XML entities with their unescaped characters &<>\"' in string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["&lt;b&gt;"], "<b>".into()),
+            Example::new(&["a &amp; b"], "a & b".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s.contains('&') && s.contains(';') {
             0.41
@@ -22,7 +29,7 @@ impl traits::Op for XmlDecode {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         let encoded = s.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&apos;", "'").replace("&amp;", "&");
         Ok(encoded.into())
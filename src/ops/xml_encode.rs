@@ -1,19 +1,26 @@
 use crate::config::Configuration;
 use crate::errors::LibError;
+use crate::example::Example;
 use crate::input::Args;
-use crate::ops::traits;
+use crate::registry::Op;
 use crate::output::Output;
 use crate::range;
 
 pub struct XmlEncode {}
 
-impl traits::Op for XmlEncode {
-    fn name() -> &'static str { "xml-encode" }
-    fn usage() -> &'static str { "<#1 string to-encode>" }
-    fn description() -> &'static str { "replace the 5 characters &<>\"' with their pre-defined XML entities in string #1" }
-    fn acceptable_number_of_arguments() -> range::Range { range::Range::IndexIndex(1, 1) }
+impl Op for XmlEncode {
+    fn name(&self) -> &'static str { "xml-encode" }
+    fn usage(&self) -> &'static str { "<#1 string to-encode>" }
+    fn description(&self) -> &'static str { "replace the 5 characters &<>\"' with their pre-defined XML entities in string #1" }
+    fn acceptable_number_of_arguments(&self) -> range::Range { range::Range::IndexIndex(1, 1) }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(&["<b>"], "&lt;b&gt;".into()),
+            Example::new(&["a & \"b\""], "a &amp; &quot;b&quot;".into()),
+        ]
+    }
 
-    fn priority(args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+    fn priority(&self, args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         Ok(if s.contains('&') || s.contains('<') || s.contains('>') || s.contains('"') || s.contains('\'') {
             0.43
@@ -22,7 +29,7 @@ impl traits::Op for XmlEncode {
         })
     }
 
-    fn run(args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+    fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
         let s: &str = args.get(0)?.try_into()?;
         let encoded = s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('\"', "&quot;").replace('\'', "&apos;");
         Ok(encoded.into())
@@ -1,15 +1,21 @@
 //! Output module responsible for generating helpful representation
 //! for the answers to the query
 
+use crate::config::AssociationOrder;
 use crate::config::Configuration;
 use crate::config::Syntax;
 use crate::errors::LibError;
 
 use std::collections;
+use std::io::Write;
 
-/// A scalar value in the result of the operation
+use serde_json::json;
+use termcolor::{Buffer, BufferWriter, ColorChoice, WriteColor};
+
+/// A scalar value in the result of the operation.
+/// Values are ordered by type first (in the order of the variants) and by content second.
 #[non_exhaustive]
-#[derive(Clone, Debug, Hash, PartialEq)]
+#[derive(Clone, Debug, Hash, PartialEq, PartialOrd, Ord)]
 pub enum OutputValue {
     Bool(bool),
     Byte(u8),
@@ -27,6 +33,7 @@ impl OutputValue {
     }
 
     /// Create an `OutputValue` element from the provided string
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> OutputValue {
         if s.lines().count() > 1 {
             OutputValue::MultiLineText(s.to_owned())
@@ -40,17 +47,20 @@ impl OutputValue {
         match self {
             OutputValue::Bool(_) => {
                 match syntax {
-                    Syntax::Cpp | Syntax::Golang | Syntax::Human | Syntax::Java |
-                    Syntax::Kotlin | Syntax::Python | Syntax::Rust => "bool",
+                    Syntax::Cpp | Syntax::Csv | Syntax::Golang | Syntax::Html | Syntax::Human | Syntax::Java |
+                    Syntax::Kotlin | Syntax::Latex | Syntax::Markdown | Syntax::Python | Syntax::Rust | Syntax::Tsv => "bool",
                     Syntax::C => "uint8_t",
+                    Syntax::Json | Syntax::JsonLines => "boolean",
                     Syntax::Perl => "int",
                 }
             },
             OutputValue::Byte(_) => {
                 match syntax {
                     Syntax::C | Syntax::Cpp => "char",
-                    Syntax::Golang | Syntax::Human => "byte",
+                    Syntax::Csv | Syntax::Golang | Syntax::Html | Syntax::Human |
+                    Syntax::Latex | Syntax::Markdown | Syntax::Tsv => "byte",
                     Syntax::Java => "int",
+                    Syntax::Json | Syntax::JsonLines => "number",
                     Syntax::Kotlin => "UByte",
                     Syntax::Perl => "int",
                     Syntax::Python => "bytes",
@@ -61,8 +71,10 @@ impl OutputValue {
                 match syntax {
                     Syntax::C | Syntax::Cpp => "uint64_t",
                     Syntax::Golang => "int64",
-                    Syntax::Human | Syntax::Python => "int",
+                    Syntax::Csv | Syntax::Html | Syntax::Human | Syntax::Latex |
+                    Syntax::Markdown | Syntax::Python | Syntax::Tsv => "int",
                     Syntax::Java => "long",
+                    Syntax::Json | Syntax::JsonLines => "number",
                     Syntax::Kotlin => "Long",
                     Syntax::Perl => "int",
                     Syntax::Rust => "int64",
@@ -73,8 +85,10 @@ impl OutputValue {
                     Syntax::C => "char*",
                     Syntax::Cpp => "std::string",
                     Syntax::Golang => "string",
-                    Syntax::Human => "single-line-text",
+                    Syntax::Csv | Syntax::Html | Syntax::Human | Syntax::Latex |
+                    Syntax::Markdown | Syntax::Tsv => "single-line-text",
                     Syntax::Java | Syntax::Kotlin => "String",
+                    Syntax::Json | Syntax::JsonLines => "string",
                     Syntax::Perl => "q//",
                     Syntax::Python => "str",
                     Syntax::Rust => "&str",
@@ -85,8 +99,10 @@ impl OutputValue {
                     Syntax::C => "char*",
                     Syntax::Cpp => "std::string",
                     Syntax::Golang => "string",
-                    Syntax::Human => "multi-line-text",
+                    Syntax::Csv | Syntax::Html | Syntax::Human | Syntax::Latex |
+                    Syntax::Markdown | Syntax::Tsv => "multi-line-text",
                     Syntax::Java | Syntax::Kotlin => "String",
+                    Syntax::Json | Syntax::JsonLines => "string",
                     Syntax::Perl => "q//",
                     Syntax::Python => "str",
                     Syntax::Rust => "&str",
@@ -99,9 +115,15 @@ impl OutputValue {
     pub(crate) fn represent(&self, conf: &Configuration) -> String {
         match conf.syntax {
             Syntax::C | Syntax::Cpp => self.represent_c_cpp(conf),
+            Syntax::Csv => self.represent_csv(conf),
+            Syntax::Tsv => self.represent_tsv(conf),
+            Syntax::Html => self.represent_html(conf),
+            Syntax::Latex => self.represent_latex(conf),
+            Syntax::Markdown => self.represent_markdown(conf),
             Syntax::Golang => self.represent_golang(conf),
             Syntax::Human => self.represent_human(conf),
             Syntax::Java => self.represent_java(conf),
+            Syntax::Json | Syntax::JsonLines => self.represent_json(conf),
             Syntax::Kotlin => self.represent_kotlin(conf),
             Syntax::Perl => self.represent_perl(conf),
             Syntax::Python => self.represent_python(conf),
@@ -141,6 +163,23 @@ impl OutputValue {
         }
     }
 
+    /// Represent this value as field of a CSV record. Fields containing a comma,
+    /// a double quote, or a line break are quoted as specified by RFC 4180.
+    pub fn represent_csv(&self, conf: &Configuration) -> String {
+        let field = self.represent_human(conf);
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field
+        }
+    }
+
+    /// Represent this value as field of a TSV record. TSV has no quoting, so a tab, a line break,
+    /// and the backslash are escaped as ``\t``, ``\n``, ``\r``, and ``\\`` instead.
+    pub fn represent_tsv(&self, conf: &Configuration) -> String {
+        self.represent_human(conf).replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+    }
+
     pub fn represent_golang(&self, conf: &Configuration) -> String {
         match self {
             OutputValue::Bool(b) => String::from(if *b { "true" } else { "false" }),
@@ -185,6 +224,15 @@ impl OutputValue {
         }
     }
 
+    /// Represent this value as HTML text with the special characters ``&<>"'`` escaped.
+    /// Line breaks become ``<br>`` elements.
+    pub fn represent_html(&self, conf: &Configuration) -> String {
+        let text = self.represent_human(conf);
+        let escaped = text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+            .replace('"', "&quot;").replace('\'', "&#39;");
+        escaped.replace("\r\n", "\n").replace(['\r', '\n'], "<br>")
+    }
+
     pub fn represent_java(&self, conf: &Configuration) -> String {
         match self {
             OutputValue::Bool(b) => String::from(if *b { "true" } else { "false" }),
@@ -210,6 +258,48 @@ impl OutputValue {
         }
     }
 
+    /// Represent this value as LaTeX text in a table cell with the special characters ``\&%$#_{}~^`` escaped.
+    /// Multi-line text is stacked with ``\shortstack``.
+    pub fn represent_latex(&self, conf: &Configuration) -> String {
+        let text = self.represent_human(conf);
+        let mut escaped = String::with_capacity(text.len());
+        for chr in text.chars() {
+            match chr {
+                '\\' => escaped.push_str("\\textbackslash{}"),
+                '&' | '%' | '$' | '#' | '_' | '{' | '}' => { escaped.push('\\'); escaped.push(chr); },
+                '~' => escaped.push_str("\\textasciitilde{}"),
+                '^' => escaped.push_str("\\textasciicircum{}"),
+                '<' => escaped.push_str("\\textless{}"),
+                '>' => escaped.push_str("\\textgreater{}"),
+                '|' => escaped.push_str("\\textbar{}"),
+                '\r' => {},
+                c => escaped.push(c),
+            }
+        }
+
+        if escaped.contains('\n') {
+            format!("\\shortstack[l]{{{}}}", escaped.replace('\n', "\\\\ "))
+        } else {
+            escaped
+        }
+    }
+
+    pub fn represent_json(&self, conf: &Configuration) -> String {
+        self.to_json(conf).to_string()
+    }
+
+    /// Return this value as JSON value
+    pub(crate) fn to_json(&self, _conf: &Configuration) -> serde_json::Value {
+        // NOTE: JSON only knows decimal numbers, so we ignore the radix
+        match self {
+            OutputValue::Bool(b) => json!(b),
+            OutputValue::Byte(b) => json!(b),
+            OutputValue::Int(i) => json!(i),
+            OutputValue::SingleLineText(t) |
+            OutputValue::MultiLineText(t) => json!(t),
+        }
+    }
+
     pub fn represent_kotlin(&self, conf: &Configuration) -> String {
         let escape_string = |t: &str| {
             t.replace('\\', "\\\\").replace('\t', "\\t").replace('\x08', "\\b")
@@ -241,6 +331,22 @@ impl OutputValue {
         }
     }
 
+    /// Represent this value as content of a GitHub-flavored markdown table cell.
+    /// Characters with a meaning in markdown are escaped by a backslash and line breaks become ``<br>``.
+    pub fn represent_markdown(&self, conf: &Configuration) -> String {
+        let text = self.represent_human(conf);
+        let mut escaped = String::with_capacity(text.len());
+        for chr in text.chars() {
+            match chr {
+                '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '&' | '#' | '!' => { escaped.push('\\'); escaped.push(chr); },
+                '\r' => {},
+                '\n' => escaped.push_str("<br>"),
+                c => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
     pub fn represent_perl(&self, conf: &Configuration) -> String {
         match self {
             OutputValue::Bool(b) => String::from(if *b { "1" } else { "0" }),
@@ -377,9 +483,8 @@ pub enum Output {
     HomogeneousList{ data: Vec<OutputValue>, notes: Vec<String> },
     /// A list where the items likely have different OutputValue types (e.g. Int and Bool)
     HeterogeneousList{ data: Vec<OutputValue>, notes: Vec<String> },
-    /// Associates a key to a value
-    /// TODO: redefine as Vec<(OutputValue, OutputValue)> to allow ordered entries
-    Association{ data: collections::HashMap<OutputValue, OutputValue>, notes: Vec<String> },
+    /// Associates a key to a value. Entries are kept in the order the operation generated them.
+    Association{ data: Vec<(OutputValue, OutputValue)>, notes: Vec<String> },
     /// Creates a table.
     /// ASSUME: for every row in data { assert!(len(row) == len(column_headers)); }
     Table{ data: Vec<Vec<OutputValue>>, column_headers: Vec<String>, notes: Vec<String> },
@@ -412,6 +517,67 @@ impl Output {
         }
     }
 
+    /// Merge the outputs of running one operation once per line into one `Output`.
+    /// `outputs` is a sequence of (one-based line number, output) tuples.
+    /// If all outputs are scalars, the result is a list of them. Otherwise the result
+    /// is a table with a column “line” to indicate the line number a row originates from.
+    pub fn merge_lines(outputs: &[(usize, Output)]) -> Result<Output, LibError> {
+        let mut notes = vec![];
+        for (line_no, output) in outputs.iter() {
+            for note in output.notes() {
+                notes.push(format!("line {}: {}", line_no, note));
+            }
+        }
+
+        // (1) scalars become a list
+        if outputs.iter().all(|(_, output)| matches!(output, Output::Scalar { .. })) {
+            let values = outputs.iter().filter_map(|(_, output)| match output {
+                Output::Scalar { data, .. } => Some(data.clone()),
+                _ => None,
+            }).collect::<Vec<OutputValue>>();
+            return Ok(Output::from_value_list(&values, &notes));
+        }
+
+        // (2) anything else becomes a table
+        let mut column_headers: Option<Vec<String>> = None;
+        let mut data = vec![];
+        for (line_no, output) in outputs.iter() {
+            let line = OutputValue::Int(*line_no as i64);
+            let (headers, rows) = match output {
+                Output::Scalar { data, .. } => {
+                    (vec!["line".to_owned(), "value".to_owned()], vec![vec![line, data.clone()]])
+                },
+                Output::HomogeneousList { data, .. } |
+                Output::HeterogeneousList { data, .. } => {
+                    (vec!["line".to_owned(), "value".to_owned()], data.iter().map(|v| vec![line.clone(), v.clone()]).collect())
+                },
+                Output::Association { data, .. } => {
+                    (vec!["line".to_owned(), "key".to_owned(), "value".to_owned()], data.iter().map(|(k, v)| vec![line.clone(), k.clone(), v.clone()]).collect())
+                },
+                Output::Table { data, column_headers, .. } => {
+                    let mut headers = vec!["line".to_owned()];
+                    headers.extend(column_headers.iter().cloned());
+                    (headers, data.iter().map(|row| {
+                        let mut new_row = vec![line.clone()];
+                        new_row.extend(row.iter().cloned());
+                        new_row
+                    }).collect())
+                },
+            };
+
+            match &column_headers {
+                Some(existing) if existing != &headers => {
+                    return Err(LibError::InvalidData(format!("cannot merge output of line {} with columns {:?} into a table with columns {:?}", line_no, headers, existing)));
+                },
+                Some(_) => {},
+                None => column_headers = Some(headers),
+            }
+            data.extend(rows);
+        }
+
+        Ok(Output::Table { data, column_headers: column_headers.unwrap_or_default(), notes })
+    }
+
     /// Attach an additional note to this output
     /// (warning/imprecision note/configuration insufficiencies/…)
     #[allow(dead_code)]
@@ -427,6 +593,47 @@ impl Output {
         }
     }
 
+    /// Remove all notes attached to this output
+    pub(crate) fn clear_notes(&mut self) {
+        match self {
+            Output::Scalar { notes, .. } |
+            Output::HomogeneousList { notes, .. } |
+            Output::HeterogeneousList { notes, .. } |
+            Output::Association { notes, .. } |
+            Output::Table { notes, .. } => notes.clear(),
+        }
+    }
+
+    /// Return the notes attached to this output
+    pub fn notes(&self) -> &[String] {
+        match self {
+            Output::Scalar { notes, .. } |
+            Output::HomogeneousList { notes, .. } |
+            Output::HeterogeneousList { notes, .. } |
+            Output::Association { notes, .. } |
+            Output::Table { notes, .. } => notes,
+        }
+    }
+
+    /// Return the truth value of a boolean result: the boolean of a scalar or
+    /// whether all booleans of a non-empty list are true. `None` for non-boolean results.
+    pub fn truth_value(&self) -> Option<bool> {
+        match self {
+            Output::Scalar { data: OutputValue::Bool(b), .. } => Some(*b),
+            Output::HomogeneousList { data, .. } if !data.is_empty() => {
+                let mut all = true;
+                for value in data.iter() {
+                    match value {
+                        OutputValue::Bool(b) => all &= *b,
+                        _ => return None,
+                    }
+                }
+                Some(all)
+            },
+            _ => None,
+        }
+    }
+
     fn is_homogeneous(&self) -> bool {
         match self {
             Output::Scalar { .. } => true,
@@ -480,25 +687,107 @@ impl Output {
     }
 
     /// Represent this `Output` in stdout & stderr.
-    ///
-    /// NOTE: Sadly, I could not implement this as part of some `fmt` trait, because
-    /// library `termcolor` needs to write to stdout/stderr directly.
+    /// Notes are written to stderr before the representation is written to stdout.
     pub fn print(&self, conf: &Configuration) -> Err {
+        let col = &conf.color_scheme;
+        let stdout = BufferWriter::stdout(col.color_choice());
+        let mut buffer = stdout.buffer();
+        let notes = self.render_to(&mut buffer, conf)?;
+
+        let mut stderr = col.stderr();
+        for note in notes.iter() {
+            col.note_label(&mut stderr, "NOTE")?;
+            writeln!(stderr, ": {}", note)?;
+        }
+        stdout.print(&buffer)?;
+        Ok(())
+    }
+
+    /// Write the representation of this `Output` in the syntax and color scheme of `conf` to `out`.
+    /// Returns the notes not contained in the representation (i.e. all notes except for JSON syntaxes)
+    /// together with notes about limitations of the syntax.
+    pub fn render_to(&self, out: &mut dyn WriteColor, conf: &Configuration) -> Result<Vec<String>, LibError> {
+        let mut notes = vec![];
+        self.reduce(conf).render_internally(out, conf, &mut notes)?;
+
+        // NOTE: nested outputs (e.g. rows of a table) might report the same limitation repeatedly
+        let mut unique_notes: Vec<String> = vec![];
+        for note in notes {
+            if !unique_notes.contains(&note) {
+                unique_notes.push(note);
+            }
+        }
+        Ok(unique_notes)
+    }
+
+    /// Return the representation of this `Output` as written by `render_to` without the notes.
+    /// ANSI escape sequences are included unless the color scheme of `conf` disables colors
+    /// (the string is treated like a stream which is not a terminal).
+    pub fn to_string(&self, conf: &Configuration) -> String {
+        let mut buffer = match conf.color_scheme.color_choice_for(false) {
+            ColorChoice::Never => Buffer::no_color(),
+            _ => Buffer::ansi(),
+        };
+        self.render_to(&mut buffer, conf).expect("writing to memory does not fail");
+        String::from_utf8_lossy(buffer.as_slice()).into_owned()
+    }
+
+    /// Apply the association order, and the selection of `item` and `column` specified in `Configuration`
+    pub(crate) fn reduce(&self, conf: &Configuration) -> Output {
+        if let Output::Association { data, notes } = self {
+            if conf.association_order != AssociationOrder::Insertion {
+                let mut sorted = data.clone();
+                match conf.association_order {
+                    AssociationOrder::Key => sorted.sort_by(|a, b| a.0.cmp(&b.0)),
+                    AssociationOrder::Value => sorted.sort_by(|a, b| a.1.cmp(&b.1)),
+                    _ => {},
+                }
+                let sorted_output = Output::Association { data: sorted, notes: notes.clone() };
+                return sorted_output.reduce(&Configuration { association_order: AssociationOrder::Insertion, ..conf.clone() });
+            }
+        }
+
         if let Some(idx) = conf.item {
             if let Some(col) = &conf.column {
-                self.reduce_by_column(conf, col).reduce_by_index(conf, idx).print_internally(conf)
+                self.reduce_by_column(conf, col).reduce_by_index(conf, idx)
             } else {
-                self.reduce_by_index(conf, idx).print_internally(conf)
+                self.reduce_by_index(conf, idx)
             }
         } else {
             if let Some(col) = &conf.column {
-                self.reduce_by_column(conf, col).print_internally(conf)
+                self.reduce_by_column(conf, col)
             } else {
-                self.print_internally(conf)
+                self.clone()
             }
         }
     }
 
+    /// Represent this `Output` as JSON object with its type name, data and notes on a single line
+    pub fn represent_json(&self, conf: &Configuration) -> String {
+        self.to_json(conf).to_string()
+    }
+
+    /// Return this `Output` as JSON object with its type name, data and notes
+    pub(crate) fn to_json(&self, conf: &Configuration) -> serde_json::Value {
+        let json_list = |values: &[OutputValue]| values.iter().map(|v| v.to_json(conf)).collect::<Vec<serde_json::Value>>();
+        let notes = self.notes();
+
+        match self {
+            Output::Scalar { data, .. } => json!({"type": "scalar", "data": data.to_json(conf), "notes": notes}),
+            Output::HomogeneousList { data, .. } => json!({"type": "homogeneous-list", "data": json_list(data), "notes": notes}),
+            Output::HeterogeneousList { data, .. } => json!({"type": "heterogeneous-list", "data": json_list(data), "notes": notes}),
+            Output::Association { data, .. } => {
+                // NOTE: JSON object keys must be strings, so we represent an association as list of pairs
+                let pairs = data.iter().map(|(key, value)| json_list(&[key.clone(), value.clone()])).collect::<Vec<_>>();
+                json!({"type": "association", "data": pairs, "notes": notes})
+            },
+            Output::Table { data, column_headers, .. } => {
+                let rows = data.iter().map(|row| json_list(row)).collect::<Vec<_>>();
+                json!({"type": "table", "column_headers": column_headers, "data": rows, "notes": notes})
+            },
+        }
+    }
+
     fn reduce_by_index(&self, _conf: &Configuration, index: isize) -> Output {
         // `item` selects the `item`-th item of a list
         if let Output::HomogeneousList{ data, .. } | Output::HeterogeneousList{ data, .. } = self {
@@ -599,143 +888,137 @@ impl Output {
         }
     }
 
-    fn print_internally(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
-
-        match self {
-            Output::Scalar { notes, .. } |
-            Output::HomogeneousList { notes, .. } |
-            Output::HeterogeneousList { notes, .. } |
-            Output::Association { notes, .. } |
-            Output::Table { notes, .. } => {
-                for note in notes {
-                    col.note_label("NOTE")?;
-                    eprintln!(": {}", note);
-                }
-            }
+    fn render_internally(&self, out: &mut dyn WriteColor, conf: &Configuration, notes: &mut Vec<String>) -> Err {
+        // NOTE: JSON syntaxes embed the notes in the JSON document
+        if !matches!(conf.syntax, Syntax::Json | Syntax::JsonLines) {
+            notes.extend(self.notes().iter().cloned());
         }
 
-        // TODO provide variable name argument to `print_` functions
+        // TODO provide variable name argument to `render_` functions
         match conf.syntax {
-            Syntax::C | Syntax::Cpp => self.print_c_cpp(conf),
-            Syntax::Golang => self.print_golang(conf),
-            Syntax::Human => self.print_human(conf),
-            Syntax::Java => self.print_java(conf),
-            Syntax::Kotlin => self.print_kotlin(conf),
-            Syntax::Perl => self.print_perl(conf),
-            Syntax::Python => self.print_python(conf),
-            Syntax::Rust => self.print_rust(conf),
+            Syntax::C | Syntax::Cpp => self.render_c_cpp(out, conf, notes),
+            Syntax::Csv | Syntax::Tsv => self.render_csv(out, conf, notes),
+            Syntax::Html => self.render_html(out, conf, notes),
+            Syntax::Latex => self.render_latex(out, conf, notes),
+            Syntax::Markdown => self.render_markdown(out, conf, notes),
+            Syntax::Golang => self.render_golang(out, conf, notes),
+            Syntax::Human => self.render_human(out, conf, notes),
+            Syntax::Java => self.render_java(out, conf, notes),
+            Syntax::Json | Syntax::JsonLines => self.render_json(out, conf, notes),
+            Syntax::Kotlin => self.render_kotlin(out, conf, notes),
+            Syntax::Perl => self.render_perl(out, conf, notes),
+            Syntax::Python => self.render_python(out, conf, notes),
+            Syntax::Rust => self.render_rust(out, conf, notes),
         }?;
 
         Ok(())
     }
 
-    fn print_c_cpp(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
+    fn render_c_cpp(&self, out: &mut dyn WriteColor, conf: &Configuration, notes: &mut Vec<String>) -> Err {
+        let col = &conf.color_scheme;
 
         match self {
             Output::Scalar { data, .. } => {
-                println!("{}", data.represent_c_cpp(conf));
+                writeln!(out, "{}", data.represent_c_cpp(conf))?;
             },
             Output::HomogeneousList { data,  .. } => {
                 if data.is_empty() {
-                    print!("int ");
-                    col.keyword("list")?;
-                    print!("[0] = ");
-                    col.inner_wrapper("{}")?;
-                    println!(";");
+                    write!(out, "int ")?;
+                    col.keyword(out, "list")?;
+                    write!(out, "[0] = ")?;
+                    col.inner_wrapper(out, "{}")?;
+                    writeln!(out, ";")?;
                 } else {
-                    print!("{} ", data[0].typename(conf));
-                    col.keyword("list")?;
-                    print!("[{}] = ", data.len());
-                    col.inner_wrapper("{")?;
+                    write!(out, "{} ", data[0].typename(conf))?;
+                    col.keyword(out, "list")?;
+                    write!(out, "[{}] = ", data.len())?;
+                    col.inner_wrapper(out, "{")?;
                     for (i, elem) in data.iter().enumerate() {
-                        print!("{}", elem.represent_c_cpp(conf));
+                        write!(out, "{}", elem.represent_c_cpp(conf))?;
 
                         if i != data.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper("}")?;
-                    println!(";");
+                    col.inner_wrapper(out, "}")?;
+                    writeln!(out, ";")?;
                 }
             },
             Output::HeterogeneousList { data, .. } => {
-                col.note_label("NOTE")?;
-                eprintln!(": heterogeneous lists cannot be handled in this syntax!");
+                notes.push("heterogeneous lists cannot be handled in this syntax!".to_owned());
 
                 if data.is_empty() {
-                    print!("int ");
-                    col.keyword("list")?;
-                    print!("[0] = ");
-                    col.inner_wrapper("{}")?;
-                    println!(";");
+                    write!(out, "int ")?;
+                    col.keyword(out, "list")?;
+                    write!(out, "[0] = ")?;
+                    col.inner_wrapper(out, "{}")?;
+                    writeln!(out, ";")?;
                     return Ok(());
                 }
 
-                print!("void* ");
-                col.keyword("list")?;
-                print!("[{}] = ", data.len());
-                col.inner_wrapper("{")?;
+                write!(out, "void* ")?;
+                col.keyword(out, "list")?;
+                write!(out, "[{}] = ", data.len())?;
+                col.inner_wrapper(out, "{")?;
                 for (i, elem) in data.iter().enumerate() {
-                    print!("{}", elem.represent_c_cpp(conf));
+                    write!(out, "{}", elem.represent_c_cpp(conf))?;
 
                     if i != data.len() - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper("}")?;
-                println!(";");
+                col.inner_wrapper(out, "}")?;
+                writeln!(out, ";")?;
             },
             Output::Association { data, .. } => {
                 if data.is_empty() {
-                    print!("int ");
-                    col.keyword("keys")?;
-                    print!("[0] = ");
-                    col.inner_wrapper("{}")?;
-                    println!(";");
-                    print!("int ");
-                    col.keyword("values")?;
-                    print!("[0] = ");
-                    col.inner_wrapper("{}")?;
-                    println!(";");
+                    write!(out, "int ")?;
+                    col.keyword(out, "keys")?;
+                    write!(out, "[0] = ")?;
+                    col.inner_wrapper(out, "{}")?;
+                    writeln!(out, ";")?;
+                    write!(out, "int ")?;
+                    col.keyword(out, "values")?;
+                    write!(out, "[0] = ")?;
+                    col.inner_wrapper(out, "{}")?;
+                    writeln!(out, ";")?;
                     return Ok(());
                 }
 
-                let keys = &Vec::from_iter(data.keys().cloned());
-                let values = &Vec::from_iter(data.values().cloned());
+                let keys = &data.iter().map(|(key, _)| key.clone()).collect::<Vec<OutputValue>>();
+                let values = &data.iter().map(|(_, value)| value.clone()).collect::<Vec<OutputValue>>();
 
                 let key_list = Output::from_value_list(keys, &[]);
                 let value_list = Output::from_value_list(values, &[]);
 
-                println!("// {} keys and values", data.len());
+                writeln!(out, "// {} keys and values", data.len())?;
                 // NOTE: no proper variable name for two lists, no improved coloring, …
                 //       I did not put a lot of effort into this.
-                key_list.print_c_cpp(conf)?;
-                value_list.print_c_cpp(conf)?;
+                key_list.render_c_cpp(out, conf, notes)?;
+                value_list.render_c_cpp(out, conf, notes)?;
             },
             Output::Table { data, column_headers, .. } => {
                 // generate representation
-                print!("const char* ");
-                col.keyword("headers")?;
-                print!("[{}] = ", column_headers.len());
-                col.inner_wrapper("{")?;
+                write!(out, "const char* ")?;
+                col.keyword(out, "headers")?;
+                write!(out, "[{}] = ", column_headers.len())?;
+                col.inner_wrapper(out, "{")?;
                 for (i, description) in column_headers.iter().enumerate() {
                     let header = OutputValue::SingleLineText(description.to_owned());
-                    print!("{}", header.represent_c_cpp(conf));
+                    write!(out, "{}", header.represent_c_cpp(conf))?;
                     if i != column_headers.len() - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper("}")?;
-                println!(";");
+                col.inner_wrapper(out, "}")?;
+                writeln!(out, ";")?;
 
                 if data.is_empty() {
-                    print!("int ");
-                    col.keyword("table")?;
-                    print!("[0][0] = ");
-                    col.inner_wrapper("{}")?;
-                    println!(";");
+                    write!(out, "int ")?;
+                    col.keyword(out, "table")?;
+                    write!(out, "[0][0] = ")?;
+                    col.inner_wrapper(out, "{}")?;
+                    writeln!(out, ";")?;
                     return Ok(());
                 }
 
@@ -743,98 +1026,181 @@ impl Output {
                 //       I did not put a lot of effort into this.
                 for row in data.iter() {
                     let list = Output::from_value_list(row, &[]);
-                    list.print_c_cpp(conf)?;
+                    list.render_c_cpp(out, conf, notes)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    fn render_csv(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        // NOTE: RFC 4180 terminates CSV records with CRLF, whereas TSV uses LF and escapes line breaks;
+        //       the output is data, so no colors are used
+        let (delimiter, terminator) = if conf.syntax == Syntax::Tsv { ("\t", "\n") } else { (",", "\r\n") };
+        let mut record = |fields: &[OutputValue]| -> Err {
+            let repr = fields.iter()
+                .map(|f| if conf.syntax == Syntax::Tsv { f.represent_tsv(conf) } else { f.represent_csv(conf) })
+                .collect::<Vec<String>>();
+            write!(out, "{}{}", repr.join(delimiter), terminator)?;
+            Ok(())
+        };
+        let header = |names: &[&str]| names.iter().map(|n| OutputValue::from_str(n)).collect::<Vec<OutputValue>>();
+
+        match self {
+            Output::Scalar { data, .. } => {
+                record(&header(&["value"]))?;
+                record(std::slice::from_ref(data))?;
+            },
+            Output::HomogeneousList { data, .. } |
+            Output::HeterogeneousList { data, .. } => {
+                record(&header(&["value"]))?;
+                for elem in data.iter() {
+                    record(std::slice::from_ref(elem))?;
+                }
+            },
+            Output::Association { data, .. } => {
+                record(&header(&["key", "value"]))?;
+                for (key, value) in data.iter() {
+                    record(&[key.clone(), value.clone()])?;
+                }
+            },
+            Output::Table { data, column_headers, .. } => {
+                record(&header(&column_headers.iter().map(|h| h.as_str()).collect::<Vec<&str>>()))?;
+                for row in data.iter() {
+                    record(row)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    fn render_html(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        let mut table = |headers: &[&str], rows: &mut dyn Iterator<Item=Vec<&OutputValue>>| -> Err {
+            writeln!(out, "<table>")?;
+            writeln!(out, "<thead>")?;
+            let cells = headers.iter().map(|h| format!("<th>{}</th>", OutputValue::from_str(h).represent_html(conf))).collect::<String>();
+            writeln!(out, "<tr>{}</tr>", cells)?;
+            writeln!(out, "</thead>")?;
+            writeln!(out, "<tbody>")?;
+            for row in rows {
+                let cells = row.iter().map(|v| format!("<td>{}</td>", v.represent_html(conf))).collect::<String>();
+                writeln!(out, "<tr>{}</tr>", cells)?;
+            }
+            writeln!(out, "</tbody>")?;
+            writeln!(out, "</table>")?;
+            Ok(())
+        };
+
+        match self {
+            Output::Scalar { data, .. } => {
+                writeln!(out, "<p>{}</p>", data.represent_html(conf))?;
+            },
+            Output::HomogeneousList { data, .. } |
+            Output::HeterogeneousList { data, .. } => {
+                writeln!(out, "<ul>")?;
+                for elem in data.iter() {
+                    writeln!(out, "<li>{}</li>", elem.represent_html(conf))?;
                 }
+                writeln!(out, "</ul>")?;
+            },
+            Output::Association { data, .. } => {
+                table(&["key", "value"], &mut data.iter().map(|(k, v)| vec![k, v]))?;
+            },
+            Output::Table { data, column_headers, .. } => {
+                let headers = column_headers.iter().map(|h| h.as_str()).collect::<Vec<&str>>();
+                table(&headers, &mut data.iter().map(|row| row.iter().collect()))?;
             },
         }
 
         Ok(())
     }
 
-    fn print_golang(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
+    fn render_golang(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        let col = &conf.color_scheme;
 
         match self {
             Output::Scalar { data, .. } => {
-                println!("{}", data.represent_golang(conf));
+                writeln!(out, "{}", data.represent_golang(conf))?;
             },
             Output::HomogeneousList{ data, .. } => {
                 if data.is_empty() {
-                    print!("[]");
-                    col.keyword("int64")?;
-                    col.inner_wrapper("{}")?;
-                    println!(";");
+                    write!(out, "[]")?;
+                    col.keyword(out, "int64")?;
+                    col.inner_wrapper(out, "{}")?;
+                    writeln!(out, ";")?;
                 } else {
-                    print!("[]");
-                    col.keyword(data[0].typename(conf))?;
-                    col.inner_wrapper("{")?;
+                    write!(out, "[]")?;
+                    col.keyword(out, data[0].typename(conf))?;
+                    col.inner_wrapper(out, "{")?;
                     for (i, elem) in data.iter().enumerate() {
-                        print!("{}", elem.represent_golang(conf));
+                        write!(out, "{}", elem.represent_golang(conf))?;
 
                         if i != data.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper("}")?;
-                    println!("");
+                    col.inner_wrapper(out, "}")?;
+                    writeln!(out)?;
                 }
             },
             Output::HeterogeneousList{ data, .. } => {
                 if data.is_empty() {
-                    print!("[]");
-                    col.keyword("any")?;
-                    col.inner_wrapper("{}")?;
-                    println!(";");
+                    write!(out, "[]")?;
+                    col.keyword(out, "any")?;
+                    col.inner_wrapper(out, "{}")?;
+                    writeln!(out, ";")?;
                 } else {
-                    print!("[]");
-                    col.keyword("any")?;
-                    col.inner_wrapper("{")?;
+                    write!(out, "[]")?;
+                    col.keyword(out, "any")?;
+                    col.inner_wrapper(out, "{")?;
                     for (i, elem) in data.iter().enumerate() {
-                        print!("{}", elem.represent_golang(conf));
+                        write!(out, "{}", elem.represent_golang(conf))?;
 
                         if i != data.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper("}")?;
-                    println!("");
+                    col.inner_wrapper(out, "}")?;
+                    writeln!(out)?;
                 }
             },
             Output::Association { data, .. } => {
                 // TODO introduce special case if data.is_homogeneous()?
-                print!("map[");
-                col.keyword("any")?;
-                print!("]");
-                col.keyword("any")?;
-                col.outer_wrapper("{")?;
+                write!(out, "map[")?;
+                col.keyword(out, "any")?;
+                write!(out, "]")?;
+                col.keyword(out, "any")?;
+                col.outer_wrapper(out, "{")?;
                 for (i, (key, value)) in data.iter().enumerate() {
-                    print!("{}", key.represent_golang(conf));
+                    write!(out, "{}", key.represent_golang(conf))?;
 
-                    col.inner_separator(": ")?;
+                    col.inner_separator(out, ": ")?;
 
-                    print!("{}", value.represent_golang(conf));
+                    write!(out, "{}", value.represent_golang(conf))?;
 
                     if i != data.len() - 1 {
-                        col.outer_separator(", ")?;
+                        col.outer_separator(out, ", ")?;
                     }
                 }
-                col.outer_wrapper("}")?;
-                println!("");
+                col.outer_wrapper(out, "}")?;
+                writeln!(out)?;
             },
             Output::Table { data, column_headers, .. } => {
                 // generate representation
-                print!("header := []");
-                col.keyword("string")?;
-                col.inner_wrapper("{")?;
+                write!(out, "header := []")?;
+                col.keyword(out, "string")?;
+                col.inner_wrapper(out, "{")?;
                 for (i, description) in column_headers.iter().enumerate() {
                     let header = OutputValue::SingleLineText(description.to_owned());
-                    print!("{}", header.represent_golang(conf));
+                    write!(out, "{}", header.represent_golang(conf))?;
                     if i != column_headers.len() - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper("}")?;
-                println!("");
+                col.inner_wrapper(out, "}")?;
+                writeln!(out)?;
 
                 // are all types the same?
                 let mut typename = "";
@@ -849,80 +1215,71 @@ impl Output {
                     typename = "any";
                 }
 
-                print!("[][]");
-                col.keyword(typename)?;
-                col.outer_wrapper("{")?;
+                write!(out, "[][]")?;
+                col.keyword(out, typename)?;
+                col.outer_wrapper(out, "{")?;
                 for (i, row) in data.iter().enumerate() {
                     let list = Output::from_value_list(row, &[]);
-                    list.print_golang(conf)?;
+                    list.render_golang(out, conf, _notes)?;
                     if i != row.len() - 1 {
-                        col.outer_separator(", ")?;
+                        col.outer_separator(out, ", ")?;
                     }
                 }
-                col.outer_wrapper("}")?;
-                println!("");
+                col.outer_wrapper(out, "}")?;
+                writeln!(out)?;
             },
         }
 
         Ok(())
     }
 
-    fn print_human(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
+    fn render_human(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        let col = &conf.color_scheme;
 
         match self {
             Output::Scalar{ data: scalar, .. } => {
-                println!("{}", scalar.represent_human(conf));
+                writeln!(out, "{}", scalar.represent_human(conf))?;
             },
             Output::HomogeneousList{ data: list, .. } |
             Output::HeterogeneousList{ data: list, .. } => {
                 //let mut any_is_multiline = false;
-                col.inner_wrapper("[ ")?;
+                col.inner_wrapper(out, "[ ")?;
                 for (i, elem) in list.iter().enumerate() {
-                    println!("{}", elem.represent_human(conf));
+                    writeln!(out, "{}", elem.represent_human(conf))?;
 
                     if i != list.len() - 1 {
-                        col.inner_separator("| ")?;
+                        col.inner_separator(out, "| ")?;
                     }
                 }
-                col.inner_wrapper("]")?;
-                println!("");
+                col.inner_wrapper(out, "]")?;
+                writeln!(out)?;
             },
             Output::Association{ data: assoc, .. } => {
-                // NOTE: humans prefer it sorted
-                //       for other syntaxes I won't sort though, because the operation is sufficiently expensive
-                let mut key_order = Vec::from_iter(assoc.keys());
-                let mut max_key_width = 0;
-                key_order.sort_by_key(|key| {
-                    let repr = key.represent_human(conf);
-                    max_key_width = max_key_width.max(repr.chars().count());
-                    repr
-                });
-
-                col.outer_wrapper("{ ")?;
-                for (i, key) in key_order.iter().enumerate() {
-                    let value = assoc.get(key).unwrap();
+                let max_key_width = assoc.iter().map(|(key, _)| key.represent_human(conf).chars().count()).max().unwrap_or(0);
+
+                col.outer_wrapper(out, "{ ")?;
+                for (i, (key, value)) in assoc.iter().enumerate() {
                     if i != 0 {
-                        println!();
-                        col.outer_separator("| ")?;
+                        writeln!(out)?;
+                        col.outer_separator(out, "| ")?;
                     }
 
                     if max_key_width > 0 && max_key_width < 80 {
                         let repr_key = key.represent_human(conf);
-                        print!("{}", repr_key);
-                        col.inner_separator("::  ")?;
+                        write!(out, "{}", repr_key)?;
+                        col.inner_separator(out, "::  ")?;
                         let already_shown = repr_key.chars().count() + 4;
                         let to_show = 8 - (already_shown % 8);
-                        print!("{}", " ".repeat(to_show));
+                        write!(out, "{}", " ".repeat(to_show))?;
                     } else {
-                        print!("{}", key.represent_human(conf));
-                        col.inner_separator(":: ")?;
+                        write!(out, "{}", key.represent_human(conf))?;
+                        col.inner_separator(out, ":: ")?;
                     }
-                    print!("{}", value.represent_human(conf));
+                    write!(out, "{}", value.represent_human(conf))?;
                 }
-                println!("");
-                col.outer_wrapper("}")?;
-                println!("");
+                writeln!(out)?;
+                col.outer_wrapper(out, "}")?;
+                writeln!(out)?;
             },
             Output::Table{ column_headers, data: table_data, .. } => {
                 // compute column widths
@@ -938,24 +1295,24 @@ impl Output {
 
                 // generate representation
                 for (description, width) in column_headers.iter().zip(&column_widths) {
-                    col.keyword(&format!("{0: <width$}", description, width=width))?;
-                    print!(" ");
+                    col.keyword(out, &format!("{0: <width$}", description, width=width))?;
+                    write!(out, " ")?;
                 }
-                println!();
+                writeln!(out)?;
                 for width in column_widths.iter() {
-                    col.inner_separator(&format!("{}", "─".repeat(*width)))?;
-                    print!(" ");
+                    col.inner_separator(out, &"─".repeat(*width))?;
+                    write!(out, " ")?;
                 }
-                println!();
+                writeln!(out)?;
 
                 // TODO continuous line below table header?
 
                 for row in table_data.iter() {
                     for (column, width) in row.iter().zip(&column_widths) {
-                        print!("{: <width$}", column.represent_human(conf), width=width);
-                        print!(" ");
+                        write!(out, "{: <width$}", column.represent_human(conf), width=width)?;
+                        write!(out, " ")?;
                     }
-                    println!();
+                    writeln!(out)?;
                 }
             },
         }
@@ -963,285 +1320,357 @@ impl Output {
         Ok(())
     }
 
-    fn print_java(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
+    fn render_java(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        let col = &conf.color_scheme;
 
         match self {
             Output::Scalar { data, .. } => {
-                println!("{}", data.represent_java(conf));
+                writeln!(out, "{}", data.represent_java(conf))?;
             },
             Output::HomogeneousList { data,  .. } => {
                 if data.is_empty() {
-                    print!("new ");
-                    col.keyword("int")?;
-                    print!("[]");
-                    col.outer_wrapper("{}")?;
-                    println!(";");
+                    write!(out, "new ")?;
+                    col.keyword(out, "int")?;
+                    write!(out, "[]")?;
+                    col.outer_wrapper(out, "{}")?;
+                    writeln!(out, ";")?;
                 } else {
-                    print!("new ");
-                    col.keyword(data[0].typename(conf))?;
-                    print!("[] = ");
-                    col.inner_wrapper("{")?;
+                    write!(out, "new ")?;
+                    col.keyword(out, data[0].typename(conf))?;
+                    write!(out, "[] = ")?;
+                    col.inner_wrapper(out, "{")?;
                     for (i, elem) in data.iter().enumerate() {
-                        print!("{}", elem.represent_java(conf));
+                        write!(out, "{}", elem.represent_java(conf))?;
 
                         if i != data.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper("}")?;
-                    println!(";");
+                    col.inner_wrapper(out, "}")?;
+                    writeln!(out, ";")?;
                 }
             },
             Output::HeterogeneousList { data, .. } => {
                 if data.is_empty() {
-                    println!("List<");
-                    col.keyword("Object")?;
-                    print!("> items = new ");
-                    col.keyword("ArrayList")?;
-                    println!("();");
+                    writeln!(out, "List<")?;
+                    col.keyword(out, "Object")?;
+                    write!(out, "> items = new ")?;
+                    col.keyword(out, "ArrayList")?;
+                    writeln!(out, "();")?;
                     return Ok(());
                 }
 
-                print!("List<Object> items = ");
-                println!("List<Object> ");
-                col.keyword("items")?;
-                print!(" = Arrays.asList");
-                col.inner_wrapper("(")?;
+                write!(out, "List<Object> items = ")?;
+                writeln!(out, "List<Object> ")?;
+                col.keyword(out, "items")?;
+                write!(out, " = Arrays.asList")?;
+                col.inner_wrapper(out, "(")?;
                 for (i, elem) in data.iter().enumerate() {
-                    print!("{}", elem.represent_java(conf));
+                    write!(out, "{}", elem.represent_java(conf))?;
 
                     if i != data.len() - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper(")")?;
-                println!(";");
+                col.inner_wrapper(out, ")")?;
+                writeln!(out, ";")?;
             },
             Output::Association { data, .. } => {
                 if data.is_empty() {
-                    print!("Map<String, String> ");
-                    col.keyword("map")?;
-                    println!(" = new HashMap();");
+                    write!(out, "Map<String, String> ")?;
+                    col.keyword(out, "map")?;
+                    writeln!(out, " = new HashMap();")?;
                 } else if data.len() == 1 {
-                    let key = data.values().next().unwrap();
-                    let value = data.values().next().unwrap();
-                    print!("Collections.singletonMap");
-                    col.inner_wrapper("(")?;
-                    print!("{}", key.represent_java(conf));
-                    col.inner_separator(", ")?;
-                    print!("{}", value.represent_java(conf));
-                    col.inner_wrapper(")")?;
-                    println!("");
+                    let (key, value) = &data[0];
+                    write!(out, "Collections.singletonMap")?;
+                    col.inner_wrapper(out, "(")?;
+                    write!(out, "{}", key.represent_java(conf))?;
+                    col.inner_separator(out, ", ")?;
+                    write!(out, "{}", value.represent_java(conf))?;
+                    col.inner_wrapper(out, ")")?;
+                    writeln!(out)?;
                 } else {
-                    print!("Map<Object, Object> ");
-                    col.keyword("map")?;
-                    print!(" = Map.ofEntries");
-                    col.outer_wrapper("(")?;
-                    println!("");
+                    write!(out, "Map<Object, Object> ")?;
+                    col.keyword(out, "map")?;
+                    write!(out, " = Map.ofEntries")?;
+                    col.outer_wrapper(out, "(")?;
+                    writeln!(out)?;
                     for (key, value) in data.iter() {
-                        print!("  entry");
-                        col.inner_wrapper("(")?;
-                        print!("{}", key.represent_java(conf));
-                        col.inner_separator(", ")?;
-                        print!("{}", value.represent_java(conf));
-                        col.inner_wrapper(")")?;
-                        println!(",");
+                        write!(out, "  entry")?;
+                        col.inner_wrapper(out, "(")?;
+                        write!(out, "{}", key.represent_java(conf))?;
+                        col.inner_separator(out, ", ")?;
+                        write!(out, "{}", value.represent_java(conf))?;
+                        col.inner_wrapper(out, ")")?;
+                        writeln!(out, ",")?;
                     }
-                    col.outer_wrapper(")")?;
-                    println!(";");
+                    col.outer_wrapper(out, ")")?;
+                    writeln!(out, ";")?;
                 }
             },
             Output::Table { data, column_headers, .. } => {
                 // generate representation
-                print!("List<String> ");
-                col.keyword("headers")?;
-                print!(" = Arrays.asList");
-                col.inner_wrapper("(")?;
+                write!(out, "List<String> ")?;
+                col.keyword(out, "headers")?;
+                write!(out, " = Arrays.asList")?;
+                col.inner_wrapper(out, "(")?;
                 for (i, description) in column_headers.iter().enumerate() {
                     let header = OutputValue::SingleLineText(description.to_owned());
-                    print!("{}", header.represent_java(conf));
+                    write!(out, "{}", header.represent_java(conf))?;
                     if i != column_headers.len() - 1 {
-                        col.inner_wrapper(", ")?;
+                        col.inner_wrapper(out, ", ")?;
                     }
                 }
-                col.inner_wrapper(")")?;
-                println!(";");
+                col.inner_wrapper(out, ")")?;
+                writeln!(out, ";")?;
 
                 if data.is_empty() {
-                    print!("int[][] ");
-                    col.keyword("emptyTable")?;
-                    println!(";");
+                    write!(out, "int[][] ")?;
+                    col.keyword(out, "emptyTable")?;
+                    writeln!(out, ";")?;
                     return Ok(());
                 }
 
-                print!("Object[][] ");
-                col.keyword("table")?;
-                print!(" = ");
-                col.outer_wrapper("{")?;
-                println!("");
+                write!(out, "Object[][] ")?;
+                col.keyword(out, "table")?;
+                write!(out, " = ")?;
+                col.outer_wrapper(out, "{")?;
+                writeln!(out)?;
                 for (row_id, row) in data.iter().enumerate() {
-                    print!("  ");
-                    col.inner_wrapper("{")?;
-                    print!(" ");
+                    write!(out, "  ")?;
+                    col.inner_wrapper(out, "{")?;
+                    write!(out, " ")?;
                     for (cell_id, cell) in row.iter().enumerate() {
-                        print!("{}", cell.represent_java(conf));
+                        write!(out, "{}", cell.represent_java(conf))?;
                         if cell_id < row.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
                     if row_id < data.len() - 1 {
-                        print!(" ");
-                        col.inner_wrapper("}")?;
-                        col.outer_separator(",")?;
+                        write!(out, " ")?;
+                        col.inner_wrapper(out, "}")?;
+                        col.outer_separator(out, ",")?;
                     } else {
-                        print!(" ");
-                        col.inner_wrapper("}")?;
+                        write!(out, " ")?;
+                        col.inner_wrapper(out, "}")?;
                     }
-                    println!("");
+                    writeln!(out)?;
                 }
-                col.outer_wrapper("}")?;
-                println!(";");
+                col.outer_wrapper(out, "}")?;
+                writeln!(out, ";")?;
             },
         }
 
         Ok(())
     }
 
-    fn print_kotlin(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
+    fn render_json(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        writeln!(out, "{}", self.represent_json(conf))?;
+        Ok(())
+    }
+
+    fn render_kotlin(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        let col = &conf.color_scheme;
 
         match self {
             Output::Scalar { data, .. } => {
-                println!("{}", data.represent_kotlin(conf));
+                writeln!(out, "{}", data.represent_kotlin(conf))?;
             },
             Output::HomogeneousList { data,  .. } => {
                 if data.is_empty() {
-                    col.keyword("emptyArray")?;
-                    println!("()");
+                    col.keyword(out, "emptyArray")?;
+                    writeln!(out, "()")?;
                 } else {
-                    col.keyword("arrayOf")?;
-                    col.inner_wrapper("(")?;
+                    col.keyword(out, "arrayOf")?;
+                    col.inner_wrapper(out, "(")?;
                     for (i, elem) in data.iter().enumerate() {
-                        print!("{}", elem.represent_kotlin(conf));
+                        write!(out, "{}", elem.represent_kotlin(conf))?;
 
                         if i != data.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper(")")?;
-                    println!("");
+                    col.inner_wrapper(out, ")")?;
+                    writeln!(out)?;
                 }
             },
             Output::HeterogeneousList { data, .. } => {
                 if data.is_empty() {
-                    col.keyword("emptyList")?;
-                    println!("()");
+                    col.keyword(out, "emptyList")?;
+                    writeln!(out, "()")?;
                     return Ok(());
                 }
 
-                col.keyword("listOf")?;
-                col.inner_wrapper("(")?;
+                col.keyword(out, "listOf")?;
+                col.inner_wrapper(out, "(")?;
                 for (i, elem) in data.iter().enumerate() {
-                    print!("{}", elem.represent_kotlin(conf));
+                    write!(out, "{}", elem.represent_kotlin(conf))?;
 
                     if i != data.len() - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper(")")?;
-                println!("");
+                col.inner_wrapper(out, ")")?;
+                writeln!(out)?;
             },
             Output::Association { data, .. } => {
                 if data.is_empty() {
-                    col.keyword("emptyMap")?;
-                    println!("()");
+                    col.keyword(out, "emptyMap")?;
+                    writeln!(out, "()")?;
                     return Ok(());
                 }
 
-                col.keyword("mapOf")?;
-                col.inner_wrapper("(")?;
+                col.keyword(out, "mapOf")?;
+                col.inner_wrapper(out, "(")?;
                 for (key, value) in data.iter() {
-                    print!("{}", key.represent_kotlin(conf));
-                    col.inner_separator(" to ")?;
-                    print!("{}", value.represent_kotlin(conf));
-                    col.outer_separator(",")?;
+                    write!(out, "{}", key.represent_kotlin(conf))?;
+                    col.inner_separator(out, " to ")?;
+                    write!(out, "{}", value.represent_kotlin(conf))?;
+                    col.outer_separator(out, ",")?;
                 }
-                col.inner_wrapper(")")?;
-                println!("");
+                col.inner_wrapper(out, ")")?;
+                writeln!(out)?;
             },
             Output::Table { data, column_headers, .. } => {
                 // generate representation
-                print!("val ");
-                col.keyword("headers")?;
-                print!(" = listOf");
-                col.inner_wrapper("(")?;
+                write!(out, "val ")?;
+                col.keyword(out, "headers")?;
+                write!(out, " = listOf")?;
+                col.inner_wrapper(out, "(")?;
                 for (i, description) in column_headers.iter().enumerate() {
                     let header = OutputValue::SingleLineText(description.to_owned());
-                    print!("{}", header.represent_kotlin(conf));
+                    write!(out, "{}", header.represent_kotlin(conf))?;
                     if i != column_headers.len() - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper(")")?;
-                println!("");
+                col.inner_wrapper(out, ")")?;
+                writeln!(out)?;
 
                 if data.is_empty() {
-                    print!("val ");
-                    col.keyword("table")?;
-                    println!(" = emptyList()");
+                    write!(out, "val ")?;
+                    col.keyword(out, "table")?;
+                    writeln!(out, " = emptyList()")?;
                     return Ok(());
                 }
 
-                print!("val ");
-                col.keyword("table")?;
-                print!(" = listOf");
-                col.outer_wrapper("(")?;
-                println!("");
+                write!(out, "val ")?;
+                col.keyword(out, "table")?;
+                write!(out, " = listOf")?;
+                col.outer_wrapper(out, "(")?;
+                writeln!(out)?;
                 for (row_id, row) in data.iter().enumerate() {
-                    print!("  listOf");
-                    col.inner_wrapper("(")?;
+                    write!(out, "  listOf")?;
+                    col.inner_wrapper(out, "(")?;
                     for (cell_id, cell) in row.iter().enumerate() {
-                        print!("{}", cell.represent_kotlin(conf));
+                        write!(out, "{}", cell.represent_kotlin(conf))?;
                         if cell_id < row.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
                     if row_id < data.len() - 1 {
-                        col.inner_wrapper(")")?;
-                        println!(",");
+                        col.inner_wrapper(out, ")")?;
+                        writeln!(out, ",")?;
                     } else {
-                        col.inner_wrapper(")")?;
-                        println!("");
+                        col.inner_wrapper(out, ")")?;
+                        writeln!(out)?;
                     }
                 }
-                col.outer_wrapper(")")?;
-                println!(";");
+                col.outer_wrapper(out, ")")?;
+                writeln!(out, ";")?;
             },
         }
 
         Ok(())
     }
 
-    fn print_perl(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
+    fn render_latex(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        let mut tabular = |headers: &[&str], rows: &mut dyn Iterator<Item=Vec<&OutputValue>>| -> Err {
+            writeln!(out, "\\begin{{tabular}}{{{}}}", "l".repeat(headers.len()))?;
+            writeln!(out, "\\hline")?;
+            let cells = headers.iter().map(|h| OutputValue::from_str(h).represent_latex(conf)).collect::<Vec<String>>();
+            writeln!(out, "{} \\\\", cells.join(" & "))?;
+            writeln!(out, "\\hline")?;
+            for row in rows {
+                let cells = row.iter().map(|v| v.represent_latex(conf)).collect::<Vec<String>>();
+                writeln!(out, "{} \\\\", cells.join(" & "))?;
+            }
+            writeln!(out, "\\hline")?;
+            writeln!(out, "\\end{{tabular}}")?;
+            Ok(())
+        };
 
         match self {
             Output::Scalar { data, .. } => {
-                println!("{}", data.represent_perl(conf));
+                writeln!(out, "{}", data.represent_latex(conf))?;
+            },
+            Output::HomogeneousList { data, .. } |
+            Output::HeterogeneousList { data, .. } => {
+                tabular(&["value"], &mut data.iter().map(|v| vec![v]))?;
+            },
+            Output::Association { data, .. } => {
+                tabular(&["key", "value"], &mut data.iter().map(|(k, v)| vec![k, v]))?;
+            },
+            Output::Table { data, column_headers, .. } => {
+                let headers = column_headers.iter().map(|h| h.as_str()).collect::<Vec<&str>>();
+                tabular(&headers, &mut data.iter().map(|row| row.iter().collect()))?;
+            },
+        }
+
+        Ok(())
+    }
+
+    fn render_markdown(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        let mut table = |headers: &[&str], rows: &mut dyn Iterator<Item=Vec<&OutputValue>>| -> Err {
+            let cells = headers.iter().map(|h| OutputValue::from_str(h).represent_markdown(conf)).collect::<Vec<String>>();
+            writeln!(out, "| {} |", cells.join(" | "))?;
+            writeln!(out, "|{}", " --- |".repeat(headers.len()))?;
+            for row in rows {
+                let cells = row.iter().map(|v| v.represent_markdown(conf)).collect::<Vec<String>>();
+                writeln!(out, "| {} |", cells.join(" | "))?;
+            }
+            Ok(())
+        };
+
+        match self {
+            Output::Scalar { data, .. } => {
+                writeln!(out, "{}", data.represent_markdown(conf))?;
+            },
+            Output::HomogeneousList { data, .. } |
+            Output::HeterogeneousList { data, .. } => {
+                table(&["value"], &mut data.iter().map(|v| vec![v]))?;
+            },
+            Output::Association { data, .. } => {
+                table(&["key", "value"], &mut data.iter().map(|(k, v)| vec![k, v]))?;
+            },
+            Output::Table { data, column_headers, .. } => {
+                let headers = column_headers.iter().map(|h| h.as_str()).collect::<Vec<&str>>();
+                table(&headers, &mut data.iter().map(|row| row.iter().collect()))?;
+            },
+        }
+
+        Ok(())
+    }
+
+    fn render_perl(&self, out: &mut dyn WriteColor, conf: &Configuration, notes: &mut Vec<String>) -> Err {
+        let col = &conf.color_scheme;
+
+        match self {
+            Output::Scalar { data, .. } => {
+                writeln!(out, "{}", data.represent_perl(conf))?;
             },
             Output::HomogeneousList { data, .. } | Output::HeterogeneousList { data, .. } => {
-                col.inner_wrapper("(")?;
+                col.inner_wrapper(out, "(")?;
                 let count = data.len();
                 for (i, v) in data.iter().enumerate() {
-                    print!("{}", v.represent_perl(conf));
+                    write!(out, "{}", v.represent_perl(conf))?;
 
                     if i != count - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper(")")?;
-                println!("");
+                col.inner_wrapper(out, ")")?;
+                writeln!(out)?;
             },
             Output::Association { data, .. } => {
                 // NOTE: perl only accepts strings as keys in hashes
@@ -1249,7 +1678,7 @@ impl Output {
                 // convert keys to strings
                 let mut data_key_to_str_key = collections::HashMap::new();
                 let mut all_keys_are_strings = true;
-                for key in data.keys() {
+                for (key, _) in data.iter() {
                     if let OutputValue::MultiLineText(_) | OutputValue::SingleLineText(_) = key {
                         data_key_to_str_key.insert(key, key.to_owned());
                     } else {
@@ -1261,8 +1690,7 @@ impl Output {
                 }
 
                 if !all_keys_are_strings {
-                    col.note_label("NOTE")?;
-                    println!(": perl hashes only accept strings as keys - keys have been converted");
+                    notes.push("perl hashes only accept strings as keys - keys have been converted".to_owned());
                 }
 
                 let text_of_output_value = |v: &OutputValue| {
@@ -1287,12 +1715,11 @@ impl Output {
                 // NOTE: since we generate perl string representations, it might happen that elements are not unique.
                 //       In this case, perl will throw an error. Since we cannot prevent it, I want to inform the user about it.
                 if !new_keys_are_unique {
-                    col.note_label("NOTE")?;
-                    println!(": perl hash keys must be unique - sadly these keys are not unique but I couldn't prevent it");
+                    notes.push("perl hash keys must be unique - sadly these keys are not unique but I couldn't prevent it".to_owned());
                 }
 
                 // write content to stdout
-                col.outer_wrapper("( ")?;
+                col.outer_wrapper(out, "( ")?;
                 let count = data.len();
                 let mut i = 0;
                 for (key, value) in data.iter() {
@@ -1301,97 +1728,97 @@ impl Output {
                         None => continue,
                     };
                     if i != 0 {
-                        print!("  ");
+                        write!(out, "  ")?;
                     }
 
                     i += 1;
-                    print!("{}", new_key.represent_perl(conf));
-                    col.inner_separator(" => ")?;
-                    print!("{}", value.represent_perl(conf));
+                    write!(out, "{}", new_key.represent_perl(conf))?;
+                    col.inner_separator(out, " => ")?;
+                    write!(out, "{}", value.represent_perl(conf))?;
 
                     if i != count - 1 {
-                        col.outer_separator(",")?;
-                        println!("");
+                        col.outer_separator(out, ",")?;
+                        writeln!(out)?;
                     }
                 }
-                col.outer_wrapper(");")?;
-                println!("");
+                col.outer_wrapper(out, ");")?;
+                writeln!(out)?;
             },
             Output::Table { data, column_headers, .. } => {
                 // print column headers
                 let col_header_values = column_headers.iter().map(|v| { OutputValue::from_str(&v) }).collect::<Vec<OutputValue>>();
-                print!("my @headers = ");
-                col.inner_wrapper("(")?;
+                write!(out, "my @headers = ")?;
+                col.inner_wrapper(out, "(")?;
                 let count = col_header_values.len();
                 for (i, v) in col_header_values.iter().enumerate() {
-                    print!("{}", v.represent_perl(conf));
+                    write!(out, "{}", v.represent_perl(conf))?;
                     if i != count - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper(");")?;
-                println!("");
+                col.inner_wrapper(out, ");")?;
+                writeln!(out)?;
 
                 // print table content
-                print!("@table = ");
-                col.outer_wrapper("(")?;
-                println!("");
+                write!(out, "@table = ")?;
+                col.outer_wrapper(out, "(")?;
+                writeln!(out)?;
                 for row in data.iter() {
-                    print!("\t");
-                    col.inner_wrapper("[ ")?;
+                    write!(out, "\t")?;
+                    col.inner_wrapper(out, "[ ")?;
                     let count = row.len();
                     for (i, cell) in row.iter().enumerate() {
-                        print!("{}", cell.represent_perl(conf));
+                        write!(out, "{}", cell.represent_perl(conf))?;
                         if i != count - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper(" ],")?;
-                    println!("");
+                    col.inner_wrapper(out, " ],")?;
+                    writeln!(out)?;
                 }
-                col.outer_wrapper(");")?;
-                println!("");
+                col.outer_wrapper(out, ");")?;
+                writeln!(out)?;
             },
         }
 
         Ok(())
     }
 
-    fn print_python(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
+    fn render_python(&self, out: &mut dyn WriteColor, conf: &Configuration, _notes: &mut Vec<String>) -> Err {
+        let col = &conf.color_scheme;
 
         match self {
             Output::Scalar { data, .. } => {
-                println!("{}", data.represent_python(conf));
+                writeln!(out, "{}", data.represent_python(conf))?;
             },
             Output::HomogeneousList{ data: list, .. } |
             Output::HeterogeneousList{ data: list, .. } => {
-                col.inner_wrapper("[")?;
+                col.inner_wrapper(out, "[")?;
                 for (i, elem) in list.iter().enumerate() {
-                    print!("{}", elem.represent_python(conf));
+                    write!(out, "{}", elem.represent_python(conf))?;
 
                     if i != list.len() - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper("]")?;
-                println!("");
+                col.inner_wrapper(out, "]")?;
+                writeln!(out)?;
             },
             Output::Association { data, .. } => {
-                col.outer_wrapper("{")?;
+                col.outer_wrapper(out, "{")?;
                 for (i, (key, value)) in data.iter().enumerate() {
-                    print!("{}", key.represent_python(conf));
+                    write!(out, "{}", key.represent_python(conf))?;
 
-                    col.inner_separator(": ")?;
+                    col.inner_separator(out, ": ")?;
 
-                    print!("{}", value.represent_python(conf));
+                    write!(out, "{}", value.represent_python(conf))?;
 
                     if i != data.len() - 1 {
-                        col.outer_separator(", ")?;
+                        col.outer_separator(out, ", ")?;
                     }
                 }
-                col.outer_wrapper("}")?;
-                println!("");
+                col.outer_wrapper(out, "}")?;
+                writeln!(out)?;
             },
             Output::Table { data, column_headers, .. } => {
                 // compute column widths
@@ -1406,205 +1833,205 @@ impl Output {
                 }
 
                 // generate representation
-                col.outer_wrapper("[")?;
-                col.inner_wrapper("[")?;
+                col.outer_wrapper(out, "[")?;
+                col.inner_wrapper(out, "[")?;
                 for (description, width) in column_headers.iter().zip(&column_widths) {
                     let header = OutputValue::SingleLineText(format!("{:─^width$}", description, width=*width));
-                    print!("{}", header.represent_python(conf));
-                    col.inner_wrapper(", ")?;
+                    write!(out, "{}", header.represent_python(conf))?;
+                    col.inner_wrapper(out, ", ")?;
                 }
-                col.inner_wrapper("]")?;
-                col.outer_separator(",")?;
-                println!("");
+                col.inner_wrapper(out, "]")?;
+                col.outer_separator(out, ",")?;
+                writeln!(out)?;
 
                 for (i, row) in data.iter().enumerate() {
-                    col.inner_wrapper(" [")?;
+                    col.inner_wrapper(out, " [")?;
                     for (i, (cell, width)) in row.iter().zip(&column_widths).enumerate() {
-                        print!("{:─^width$}", cell.represent_python(conf), width=*width);
+                        write!(out, "{:─^width$}", cell.represent_python(conf), width=*width)?;
                         if i != row.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper("]")?;
+                    col.inner_wrapper(out, "]")?;
                     if i != data.len() - 1 {
-                        col.outer_separator(",")?;
+                        col.outer_separator(out, ",")?;
                     }
                 }
-                col.outer_wrapper("]")?;
-                println!("");
+                col.outer_wrapper(out, "]")?;
+                writeln!(out)?;
             },
         }
 
         Ok(())
     }
 
-    fn print_rust(&self, conf: &Configuration) -> Err {
-        let col = conf.color_scheme;
-        let repr_val = |val: &OutputValue| {
+    fn render_rust(&self, out: &mut dyn WriteColor, conf: &Configuration, notes: &mut Vec<String>) -> Err {
+        let col = &conf.color_scheme;
+        let repr_val = |out: &mut dyn WriteColor, val: &OutputValue| -> Err {
             match val {
-                OutputValue::Bool(b) => print!("Val::Bool({})", if *b { "true" } else { "false" }),
-                OutputValue::Byte(y) => print!("Val::Byte(0x{:02X})", y),
-                OutputValue::Int(i) => print!("Val::Int({})", *i),
-                OutputValue::SingleLineText(_) => print!("Val::OneLineString({}.to_owned())", val.represent_rust(conf)),
-                OutputValue::MultiLineText(_) => print!("Val::MultiLineString({}.to_owned())", val.represent_rust(conf)),
+                OutputValue::Bool(b) => write!(out, "Val::Bool({})", if *b { "true" } else { "false" })?,
+                OutputValue::Byte(y) => write!(out, "Val::Byte(0x{:02X})", y)?,
+                OutputValue::Int(i) => write!(out, "Val::Int({})", *i)?,
+                OutputValue::SingleLineText(_) => write!(out, "Val::OneLineString({}.to_owned())", val.represent_rust(conf))?,
+                OutputValue::MultiLineText(_) => write!(out, "Val::MultiLineString({}.to_owned())", val.represent_rust(conf))?,
             }
+            Ok(())
         };
 
-        let val_declaration = || -> Err {
-            println!("#[derive(Clone, Debug, Hash, PartialEq)]");
-            print!("enum ");
-            col.keyword("Val")?;
-            println!(" {{");
+        let val_declaration = |out: &mut dyn WriteColor| -> Err {
+            writeln!(out, "#[derive(Clone, Debug, Hash, PartialEq)]")?;
+            write!(out, "enum ")?;
+            col.keyword(out, "Val")?;
+            writeln!(out, " {{")?;
 
-            print!("  ");
-            col.keyword("Bool")?;
-            println!("(bool),");
+            write!(out, "  ")?;
+            col.keyword(out, "Bool")?;
+            writeln!(out, "(bool),")?;
 
-            print!("  ");
-            col.keyword("Byte")?;
-            println!("(u8),");
+            write!(out, "  ")?;
+            col.keyword(out, "Byte")?;
+            writeln!(out, "(u8),")?;
 
-            print!("  ");
-            col.keyword("Int")?;
-            println!("(i64),");
+            write!(out, "  ")?;
+            col.keyword(out, "Int")?;
+            writeln!(out, "(i64),")?;
 
-            print!("  ");
-            col.keyword("OneLineString")?;
-            println!("(String),");
+            write!(out, "  ")?;
+            col.keyword(out, "OneLineString")?;
+            writeln!(out, "(String),")?;
 
-            print!("  ");
-            col.keyword("MultiLineString")?;
-            println!("(String),");
-            println!("}}");
+            write!(out, "  ")?;
+            col.keyword(out, "MultiLineString")?;
+            writeln!(out, "(String),")?;
+            writeln!(out, "}}")?;
 
             Ok(())
         };
 
         match self {
             Output::Scalar { data, .. } => {
-                println!("{}", data.represent_rust(conf));
+                writeln!(out, "{}", data.represent_rust(conf))?;
             },
             Output::HomogeneousList { data,  .. } => {
                 if data.is_empty() {
-                    print!("let mut ");
-                    col.keyword("list")?;
-                    println!(": [i32; 0];");
+                    write!(out, "let mut ")?;
+                    col.keyword(out, "list")?;
+                    writeln!(out, ": [i32; 0];")?;
                 } else {
-                    print!("let mut ");
-                    col.keyword("array")?;
-                    print!(": [");
-                    col.keyword(data[0].typename(conf))?;
-                    print!("; ");
-                    col.keyword(&format!("{}", data.len()))?;
-                    print!("] = ");
-                    col.inner_wrapper("[")?;
+                    write!(out, "let mut ")?;
+                    col.keyword(out, "array")?;
+                    write!(out, ": [")?;
+                    col.keyword(out, data[0].typename(conf))?;
+                    write!(out, "; ")?;
+                    col.keyword(out, &format!("{}", data.len()))?;
+                    write!(out, "] = ")?;
+                    col.inner_wrapper(out, "[")?;
                     for (i, elem) in data.iter().enumerate() {
-                        print!("{}", elem.represent_rust(conf));
+                        write!(out, "{}", elem.represent_rust(conf))?;
 
                         if i != data.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper("]")?;
-                    println!(";");
+                    col.inner_wrapper(out, "]")?;
+                    writeln!(out, ";")?;
                 }
             },
             Output::HeterogeneousList { data, .. } => {
                 if data.is_empty() {
-                    print!("let mut ");
-                    col.keyword("list")?;
-                    println!(": [i32; 0];");
+                    write!(out, "let mut ")?;
+                    col.keyword(out, "list")?;
+                    writeln!(out, ": [i32; 0];")?;
 
                 } else {
-                    val_declaration()?;
+                    val_declaration(out)?;
 
-                    print!("let ");
-                    col.keyword("list")?;
-                    print!(": [Val; {}] = ", data.len());
-                    col.inner_wrapper("[")?;
+                    write!(out, "let ")?;
+                    col.keyword(out, "list")?;
+                    write!(out, ": [Val; {}] = ", data.len())?;
+                    col.inner_wrapper(out, "[")?;
                     for (i, elem) in data.iter().enumerate() {
-                        repr_val(elem);
+                        repr_val(out, elem)?;
 
                         if i != data.len() - 1 {
-                            col.inner_separator(", ")?;
+                            col.inner_separator(out, ", ")?;
                         }
                     }
-                    col.inner_wrapper("]")?;
-                    println!(";");
+                    col.inner_wrapper(out, "]")?;
+                    writeln!(out, ";")?;
                 }
             },
             Output::Association { data, .. } => {
-                col.note_label("NOTE")?;
-                println!(": heterogeneous maps cannot be handled in this syntax");
+                notes.push("heterogeneous maps cannot be handled in this syntax".to_owned());
 
                 if data.is_empty() {
-                    print!("let mut ");
-                    col.keyword("map")?;
-                    println!(": HashMap<String, String> = HashMap::new();");
+                    write!(out, "let mut ")?;
+                    col.keyword(out, "map")?;
+                    writeln!(out, ": HashMap<String, String> = HashMap::new();")?;
 
                 } else {
-                    val_declaration()?;
+                    val_declaration(out)?;
 
-                    print!("let mut ");
-                    col.keyword("map")?;
-                    println!(": HashMap<Val, Val> = HashMap::new();");
+                    write!(out, "let mut ")?;
+                    col.keyword(out, "map")?;
+                    writeln!(out, ": HashMap<Val, Val> = HashMap::new();")?;
                     for (key, value) in data.iter() {
-                        print!("map.insert");
-                        col.inner_wrapper("(")?;
-                        repr_val(key);
-                        col.inner_separator(", ")?;
-                        repr_val(value);
-                        col.inner_wrapper(")")?;
-                        println!(";");
+                        write!(out, "map.insert")?;
+                        col.inner_wrapper(out, "(")?;
+                        repr_val(out, key)?;
+                        col.inner_separator(out, ", ")?;
+                        repr_val(out, value)?;
+                        col.inner_wrapper(out, ")")?;
+                        writeln!(out, ";")?;
                     }
                 }
             },
             Output::Table { data, column_headers, .. } => {
-                val_declaration()?;
+                val_declaration(out)?;
 
                 // generate representation
-                print!("let ");
-                col.keyword("headers")?;
-                print!("[&'static str; {}] = ", column_headers.len());
-                col.inner_wrapper("[")?;
+                write!(out, "let ")?;
+                col.keyword(out, "headers")?;
+                write!(out, "[&'static str; {}] = ", column_headers.len())?;
+                col.inner_wrapper(out, "[")?;
                 for (i, description) in column_headers.iter().enumerate() {
                     let header = OutputValue::SingleLineText(description.to_owned());
-                    print!("{}", header.represent_rust(conf));
+                    write!(out, "{}", header.represent_rust(conf))?;
                     if i != column_headers.len() - 1 {
-                        col.inner_separator(", ")?;
+                        col.inner_separator(out, ", ")?;
                     }
                 }
-                col.inner_wrapper("]")?;
-                println!(";");
+                col.inner_wrapper(out, "]")?;
+                writeln!(out, ";")?;
 
                 if data.is_empty() {
-                    print!("let ");
-                    col.keyword("table")?;
-                    println!(" = vec![];");
+                    write!(out, "let ")?;
+                    col.keyword(out, "table")?;
+                    writeln!(out, " = vec![];")?;
 
                 } else {
-                    print!("let ");
-                    col.keyword("table")?;
-                    println!(": Vec<Vec<Val>> = vec!");
-                    col.outer_wrapper("[")?;
-                    println!("");
+                    write!(out, "let ")?;
+                    col.keyword(out, "table")?;
+                    writeln!(out, ": Vec<Vec<Val>> = vec!")?;
+                    col.outer_wrapper(out, "[")?;
+                    writeln!(out)?;
                     for (i, row) in data.iter().enumerate() {
-                        print!("  vec!");
-                        col.inner_wrapper("[")?;
+                        write!(out, "  vec!")?;
+                        col.inner_wrapper(out, "[")?;
                         for (j, cell) in row.iter().enumerate() {
-                            print!("{}", cell.represent_rust(conf));
+                            write!(out, "{}", cell.represent_rust(conf))?;
                             if j != row.len() - 1 {
-                                col.inner_separator(", ")?;
+                                col.inner_separator(out, ", ")?;
                             }
                         }
-                        col.inner_wrapper("]")?;
+                        col.inner_wrapper(out, "]")?;
                         if i != data.len() - 1 {
-                            col.outer_separator(",")?;
+                            col.outer_separator(out, ",")?;
                         }
-                        println!("");
+                        writeln!(out)?;
                     }
-                    col.outer_wrapper("]")?;
-                    println!(";");
+                    col.outer_wrapper(out, "]")?;
+                    writeln!(out, ";")?;
                 }
             },
         }
@@ -1613,6 +2040,7 @@ impl Output {
     }
 }
 
+
 // NOTE: simple conversions follow as syntactic sugar
 
 impl From<bool> for Output {
@@ -1658,3 +2086,124 @@ impl From<&str> for Output {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_lines() {
+        let scalars = vec![(1, Output::from(3usize)), (2, Output::from(5usize))];
+        assert_eq!(Output::merge_lines(&scalars).unwrap(), Output::HomogeneousList {
+            data: vec![OutputValue::Int(3), OutputValue::Int(5)],
+            notes: vec![],
+        });
+
+        let lists = vec![
+            (1, Output::from_value_list(&[OutputValue::Byte(104)], &[])),
+            (2, Output::from_value_list(&[OutputValue::Byte(105), OutputValue::Byte(106)], &["note".to_owned()])),
+        ];
+        assert_eq!(Output::merge_lines(&lists).unwrap(), Output::Table {
+            data: vec![
+                vec![OutputValue::Int(1), OutputValue::Byte(104)],
+                vec![OutputValue::Int(2), OutputValue::Byte(105)],
+                vec![OutputValue::Int(2), OutputValue::Byte(106)],
+            ],
+            column_headers: vec!["line".to_owned(), "value".to_owned()],
+            notes: vec!["line 2: note".to_owned()],
+        });
+    }
+
+    #[test]
+    fn test_association_order() {
+        let assoc = Output::Association {
+            data: vec![
+                (OutputValue::from_str("b"), OutputValue::Int(1)),
+                (OutputValue::from_str("c"), OutputValue::Int(0)),
+                (OutputValue::from_str("a"), OutputValue::Int(2)),
+            ],
+            notes: vec![],
+        };
+        let keys = |output: Output| match output {
+            Output::Association { data, .. } => data.into_iter().map(|(k, _)| k).collect::<Vec<OutputValue>>(),
+            _ => panic!("association expected"),
+        };
+        let mut conf = Configuration::default();
+        assert_eq!(keys(assoc.reduce(&conf)), vec![OutputValue::from_str("b"), OutputValue::from_str("c"), OutputValue::from_str("a")]);
+        conf.association_order = AssociationOrder::Key;
+        assert_eq!(keys(assoc.reduce(&conf)), vec![OutputValue::from_str("a"), OutputValue::from_str("b"), OutputValue::from_str("c")]);
+        conf.association_order = AssociationOrder::Value;
+        assert_eq!(keys(assoc.reduce(&conf)), vec![OutputValue::from_str("c"), OutputValue::from_str("b"), OutputValue::from_str("a")]);
+    }
+
+    #[test]
+    fn test_represent_json() {
+        let conf = Configuration::default();
+        let mut scalar = Output::from("say \"hi\"\n");
+        scalar.add_note("a note");
+        assert_eq!(scalar.represent_json(&conf), r#"{"type":"scalar","data":"say \"hi\"\n","notes":["a note"]}"#);
+
+        let assoc = Output::Association { data: vec![(OutputValue::Int(1), OutputValue::Bool(true))], notes: vec![] };
+        assert_eq!(assoc.represent_json(&conf), r#"{"type":"association","data":[[1,true]],"notes":[]}"#);
+    }
+
+    #[test]
+    fn test_render_to() {
+        let mut conf = Configuration { color_scheme: crate::config::ColorScheme::NoColors, ..Configuration::default() };
+        let list = Output::from_value_list(&[OutputValue::Int(1), OutputValue::from_str("a")], &["a note".to_owned()]);
+
+        conf.syntax = Syntax::Python;
+        assert_eq!(list.to_string(&conf), "[1, \"a\"]\n");
+
+        conf.syntax = Syntax::C;
+        let mut buffer = Buffer::no_color();
+        let notes = list.render_to(&mut buffer, &conf).unwrap();
+        assert_eq!(String::from_utf8_lossy(buffer.as_slice()), "void* list[2] = {1, \"a\"};\n");
+        assert_eq!(notes, vec!["a note".to_owned(), "heterogeneous lists cannot be handled in this syntax!".to_owned()]);
+
+        conf.color_scheme = crate::config::ColorScheme::RegularAndBold;
+        conf.syntax = Syntax::Json;
+        assert_eq!(list.to_string(&conf), "{\"type\":\"heterogeneous-list\",\"data\":[1,\"a\"],\"notes\":[\"a note\"]}\n");
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let mut conf = Configuration { syntax: Syntax::Csv, ..Configuration::default() };
+        let table = Output::Table {
+            data: vec![
+                vec![OutputValue::Int(1), OutputValue::from_str("a,b")],
+                vec![OutputValue::Int(2), OutputValue::from_str("say \"hi\"\nbye")],
+            ],
+            column_headers: vec!["count".to_owned(), "text".to_owned()],
+            notes: vec!["a note".to_owned()],
+        };
+        let mut buffer = Buffer::no_color();
+        assert_eq!(table.render_to(&mut buffer, &conf).unwrap(), vec!["a note".to_owned()]);
+        assert_eq!(String::from_utf8_lossy(buffer.as_slice()), "count,text\r\n1,\"a,b\"\r\n2,\"say \"\"hi\"\"\nbye\"\r\n");
+
+        conf.syntax = Syntax::Tsv;
+        assert_eq!(table.to_string(&conf), "count\ttext\n1\ta,b\n2\tsay \"hi\"\\nbye\n");
+        let list = Output::from_value_list(&[OutputValue::from_str("a\tb\\c")], &[]);
+        assert_eq!(list.to_string(&conf), "value\na\\tb\\\\c\n");
+    }
+
+    #[test]
+    fn test_render_markup() {
+        let mut conf = Configuration { syntax: Syntax::Markdown, ..Configuration::default() };
+        let assoc = Output::Association {
+            data: vec![(OutputValue::from_str("a|b"), OutputValue::from_str("<x>\ny & 50%_"))],
+            notes: vec![],
+        };
+        assert_eq!(assoc.to_string(&conf), "| key | value |\n| --- | --- |\n| a\\|b | \\<x\\><br>y \\& 50%\\_ |\n");
+
+        conf.syntax = Syntax::Html;
+        assert_eq!(assoc.to_string(&conf), "<table>\n<thead>\n<tr><th>key</th><th>value</th></tr>\n</thead>\n<tbody>\n\
+            <tr><td>a|b</td><td>&lt;x&gt;<br>y &amp; 50%_</td></tr>\n</tbody>\n</table>\n");
+        let list = Output::from_value_list(&[OutputValue::from_str("'quoted'")], &[]);
+        assert_eq!(list.to_string(&conf), "<ul>\n<li>&#39;quoted&#39;</li>\n</ul>\n");
+
+        conf.syntax = Syntax::Latex;
+        assert_eq!(assoc.to_string(&conf), "\\begin{tabular}{ll}\n\\hline\nkey & value \\\\\n\\hline\n\
+            a\\textbar{}b & \\shortstack[l]{\\textless{}x\\textgreater{}\\\\ y \\& 50\\%\\_} \\\\\n\\hline\n\\end{tabular}\n");
+    }
+}
@@ -0,0 +1,208 @@
+//! A minimal thread pool to evaluate operations concurrently.
+//!
+//! Every job gets its own time budget starting when a worker picks it up.
+//! A job exceeding its budget is abandoned: its worker keeps running detached
+//! and a replacement worker takes over the remaining jobs, such that one
+//! pathological job cannot block the others.
+//!
+//! Detached workers cannot be killed and keep consuming resources until their
+//! job finishes. Hence at most `MAX_DETACHED_WORKERS` of them are tolerated within
+//! the process (e.g. across requests in `--serve` mode). Beyond that, no replacement
+//! is spawned and once no worker is left, the remaining jobs are refused.
+
+use std::collections::HashMap;
+use std::panic;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Maximum number of detached workers running abandoned jobs before replacements are refused
+pub(crate) const MAX_DETACHED_WORKERS: usize = 16;
+
+/// Number of detached workers currently running abandoned jobs within this process
+static DETACHED_WORKERS: AtomicUsize = AtomicUsize::new(0);
+
+/// States of a job shared between its worker and the coordinating thread
+const JOB_RUNNING: u8 = 0;
+const JOB_FINISHED: u8 = 1;
+const JOB_ABANDONED: u8 = 2;
+
+/// A unit of work to be run on a worker thread
+pub(crate) type Job<T> = Box<dyn FnOnce() -> T + Send>;
+
+/// The outcome of running one job
+#[derive(Debug, PartialEq)]
+pub(crate) enum JobResult<T> {
+    /// the job finished with this result
+    Done(T),
+    /// the job did not finish within its time budget
+    TimedOut,
+    /// the job panicked
+    Panicked,
+    /// the job was not run, because too many abandoned jobs are still running
+    Refused,
+}
+
+/// Message from a worker to the coordinating thread
+enum Event<T> {
+    Started(usize),
+    Finished(usize, JobResult<T>),
+}
+
+/// Run `jobs` concurrently with a time budget of `timeout` per job.
+/// The results are returned in the order of `jobs`.
+pub(crate) fn run_jobs<T: Send + 'static>(jobs: Vec<Job<T>>, timeout: Duration) -> Vec<JobResult<T>> {
+    let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+    run_jobs_with(jobs, timeout, workers, &DETACHED_WORKERS, MAX_DETACHED_WORKERS)
+}
+
+/// Run `jobs` like `run_jobs` with at most `workers` threads. `detached` counts the detached
+/// workers and no replacement is spawned once it exceeds `max_detached`.
+fn run_jobs_with<T: Send + 'static>(jobs: Vec<Job<T>>, timeout: Duration, workers: usize, detached: &'static AtomicUsize, max_detached: usize) -> Vec<JobResult<T>> {
+    let count = jobs.len();
+    let states = Arc::new((0..count).map(|_| AtomicU8::new(JOB_RUNNING)).collect::<Vec<AtomicU8>>());
+    let (job_sender, job_receiver) = mpsc::channel::<(usize, Job<T>)>();
+    let job_receiver = Arc::new(Mutex::new(job_receiver));
+    let (event_sender, event_receiver) = mpsc::channel::<Event<T>>();
+
+    for (i, job) in jobs.into_iter().enumerate() {
+        // NOTE: the receiver is alive, so sending cannot fail
+        let _ = job_sender.send((i, job));
+    }
+    drop(job_sender);
+
+    let spawn_worker = || {
+        let jobs = Arc::clone(&job_receiver);
+        let events = event_sender.clone();
+        let states = Arc::clone(&states);
+        thread::spawn(move || loop {
+            // the lock is released before the job is run
+            let next = match jobs.lock() {
+                Ok(receiver) => receiver.recv().ok(),
+                Err(_) => None,
+            };
+            let (i, job) = match next {
+                Some(entry) => entry,
+                None => break,
+            };
+
+            if events.send(Event::Started(i)).is_err() {
+                break;
+            }
+            let result = match panic::catch_unwind(panic::AssertUnwindSafe(job)) {
+                Ok(value) => JobResult::Done(value),
+                Err(_) => JobResult::Panicked,
+            };
+            // an abandoned worker was replaced already and terminates
+            if states[i].compare_exchange(JOB_RUNNING, JOB_FINISHED, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+                detached.fetch_sub(1, Ordering::SeqCst);
+                break;
+            }
+            if events.send(Event::Finished(i, result)).is_err() {
+                break;
+            }
+        });
+    };
+
+    let mut live_workers = workers.min(count);
+    for _ in 0..live_workers {
+        spawn_worker();
+    }
+
+    let mut results = (0..count).map(|_| None).collect::<Vec<Option<JobResult<T>>>>();
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+    let mut pending = count;
+
+    while pending > 0 {
+        let now = Instant::now();
+        let wait = running.values().map(|start| (*start + timeout).saturating_duration_since(now)).min().unwrap_or(timeout);
+
+        match event_receiver.recv_timeout(wait) {
+            Ok(Event::Started(i)) => {
+                running.insert(i, Instant::now());
+            },
+            Ok(Event::Finished(i, result)) => {
+                // results of abandoned jobs arrive too late and are ignored
+                if running.remove(&i).is_some() {
+                    results[i] = Some(result);
+                    pending -= 1;
+                }
+            },
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let expired = running.iter()
+                    .filter(|(_, start)| now >= **start + timeout)
+                    .map(|(i, _)| *i)
+                    .collect::<Vec<usize>>();
+                for i in expired {
+                    // the job might have finished just now, then its result is about to arrive
+                    if states[i].compare_exchange(JOB_RUNNING, JOB_ABANDONED, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+                        continue;
+                    }
+                    running.remove(&i);
+                    results[i] = Some(JobResult::TimedOut);
+                    pending -= 1;
+                    live_workers -= 1;
+                    if detached.fetch_add(1, Ordering::SeqCst) < max_detached {
+                        spawn_worker();
+                        live_workers += 1;
+                    }
+                }
+
+                // without workers, the jobs not started yet are refused
+                if live_workers == 0 {
+                    if let Ok(receiver) = job_receiver.lock() {
+                        while let Ok((i, _)) = receiver.try_recv() {
+                            results[i] = Some(JobResult::Refused);
+                            pending -= 1;
+                        }
+                    }
+                }
+            },
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    results.into_iter().map(|r| r.unwrap_or(JobResult::TimedOut)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_jobs() {
+        let jobs: Vec<Job<usize>> = vec![
+            Box::new(|| 1),
+            Box::new(|| { thread::sleep(Duration::from_secs(5)); 2 }),
+            Box::new(|| panic!("job failed")),
+            Box::new(|| 4),
+        ];
+
+        let start = Instant::now();
+        let results = run_jobs(jobs, Duration::from_millis(200));
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert_eq!(results, vec![JobResult::Done(1), JobResult::TimedOut, JobResult::Panicked, JobResult::Done(4)]);
+        assert!(run_jobs::<()>(vec![], Duration::from_millis(1)).is_empty());
+    }
+
+    #[test]
+    fn test_detached_workers_limit() {
+        static DETACHED: AtomicUsize = AtomicUsize::new(0);
+        let jobs: Vec<Job<usize>> = vec![
+            Box::new(|| { thread::sleep(Duration::from_millis(500)); 1 }),
+            Box::new(|| 2),
+            Box::new(|| 3),
+        ];
+
+        // the only worker is abandoned and must not be replaced
+        let results = run_jobs_with(jobs, Duration::from_millis(100), 1, &DETACHED, 0);
+        assert_eq!(results, vec![JobResult::TimedOut, JobResult::Refused, JobResult::Refused]);
+        assert_eq!(DETACHED.load(Ordering::SeqCst), 1);
+
+        // the detached worker terminates once its job finished
+        thread::sleep(Duration::from_secs(1));
+        assert_eq!(DETACHED.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,271 @@
+//! Structured specification of the parameters of an operation.
+//!
+//! Every operation describes its parameters in its usage string like
+//! ``<#1 string text> [optional <#2 int width>]``. `Param::from_usage` derives the
+//! structured specification from it, which `validate` uses to check arguments before an
+//! operation is run. Thus operations can rely on well-typed arguments and users get uniform
+//! error messages.
+
+use std::fmt;
+
+use crate::errors::LibError;
+use crate::input::{Arg, Args};
+
+/// The type of value a parameter accepts
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Hash, PartialEq)]
+pub enum ParamType {
+    /// any Unicode string
+    String,
+    /// a decimal integer like ``-42``
+    Int,
+    /// ``true`` or ``false``
+    Bool,
+    /// a string consisting of exactly one Unicode scalar
+    Codepoint,
+    /// a regular expression
+    Regex,
+    /// a sequence of bytes
+    Bytes,
+    /// a Unicode string or a sequence of bytes
+    StringOrBytes,
+}
+
+impl ParamType {
+    /// Take a type name used in usage strings and return the corresponding `ParamType` (or None, if unknown)
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "string" | "str" => Some(ParamType::String),
+            "int" | "integer" => Some(ParamType::Int),
+            "bool" | "boolean" => Some(ParamType::Bool),
+            "codepoint" | "char" => Some(ParamType::Codepoint),
+            "regex" => Some(ParamType::Regex),
+            "bytes" => Some(ParamType::Bytes),
+            "string-or-bytes" => Some(ParamType::StringOrBytes),
+            _ => None,
+        }
+    }
+
+    /// Describe the values of this type in an error message like "expects {}"
+    fn describe(&self) -> &'static str {
+        match self {
+            ParamType::String => "a string",
+            ParamType::Int => "an integer",
+            ParamType::Bool => "a boolean",
+            ParamType::Codepoint => "a codepoint",
+            ParamType::Regex => "a regular expression",
+            ParamType::Bytes => "bytes",
+            ParamType::StringOrBytes => "a string or bytes",
+        }
+    }
+}
+
+impl fmt::Display for ParamType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParamType::String => "string",
+            ParamType::Int => "int",
+            ParamType::Bool => "bool",
+            ParamType::Codepoint => "codepoint",
+            ParamType::Regex => "regex",
+            ParamType::Bytes => "bytes",
+            ParamType::StringOrBytes => "string-or-bytes",
+        })
+    }
+}
+
+/// Specification of one parameter of an operation
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct Param {
+    /// a short name like ``to-analyze``
+    pub name: &'static str,
+    /// the type of value accepted
+    pub kind: ParamType,
+    /// may this argument be omitted?
+    pub optional: bool,
+    /// the value the operation uses if the argument is omitted (only informative)
+    pub default: Option<&'static str>,
+    /// does this parameter accept an arbitrary number of arguments? Must be the last parameter.
+    /// If `optional` is false, at least one argument is required.
+    pub variadic: bool,
+}
+
+impl Param {
+    /// Create a required parameter with `name` of type `kind`
+    pub fn new(name: &'static str, kind: ParamType) -> Self {
+        Self { name, kind, optional: false, default: None, variadic: false }
+    }
+
+    /// Make this parameter optional with the given default value
+    pub fn with_default(mut self, default: &'static str) -> Self {
+        self.optional = true;
+        self.default = Some(default);
+        self
+    }
+
+    /// Make the parameters in `params` called like the given names optional with the given default values
+    pub fn with_defaults(params: Vec<Param>, defaults: &[(&str, &'static str)]) -> Vec<Param> {
+        params.into_iter()
+            .map(|param| match defaults.iter().find(|(name, _)| *name == param.name) {
+                Some((_, default)) => param.with_default(default),
+                None => param,
+            })
+            .collect()
+    }
+
+    /// Derive the parameters from a usage string like
+    /// ``<#1 string base> [<#2 string compare> 1 or more times]``.
+    /// Every parameter is given as ``<#ID TYPE NAME>``. A preceding ``[`` makes it optional.
+    /// A subsequent ``… times`` makes it variadic, where ``one or more`` and ``1 or more`` require one argument.
+    ///
+    /// Panics if a parameter has an unknown type. Usage strings are static, so a test
+    /// checks the usage strings of all operations instead of every caller handling this case.
+    pub fn from_usage(usage: &'static str) -> Vec<Param> {
+        let mut params = vec![];
+        let mut prev_end = 0;
+
+        while let Some(offset) = usage[prev_end..].find("<#") {
+            let start = prev_end + offset;
+            let end = match usage[start..].find('>') {
+                Some(e) => start + e,
+                None => break,
+            };
+            let next_start = usage[end..].find("<#").map(|n| end + n).unwrap_or(usage.len());
+
+            let before = &usage[prev_end..start];
+            let after = &usage[end + 1..next_start];
+
+            // parse "#ID TYPE NAME"
+            let mut words = usage[start + 2..end].split_whitespace().skip(1);
+            let kind = match words.next() {
+                Some(type_name) => ParamType::by_name(type_name)
+                    .unwrap_or_else(|| panic!("usage string '{}' specifies unknown parameter type '{}'", usage, type_name)),
+                None => panic!("usage string '{}' specifies a parameter without type", usage),
+            };
+            let name = words.next().unwrap_or("");
+
+            let variadic = after.contains("times");
+            let at_least_one = after.contains("one or more") || after.contains("1 or more");
+            let optional = if variadic { !at_least_one } else { before.contains('[') };
+
+            params.push(Param { name, kind, optional, default: None, variadic });
+            prev_end = end + 1;
+        }
+
+        params
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind, self.name)?;
+        if let Some(default) = self.default {
+            write!(f, " (default: {})", default)?;
+        }
+        match (self.optional, self.variadic) {
+            (true, true) => write!(f, ", zero or more times"),
+            (false, true) => write!(f, ", one or more times"),
+            (true, false) => write!(f, ", optional"),
+            (false, false) => Ok(()),
+        }
+    }
+}
+
+/// Check that every argument satisfies the type of its corresponding parameter.
+/// Arguments beyond the last parameter are assigned to it if it is variadic, and are ignored otherwise.
+pub(crate) fn validate(params: &[Param], args: &Args) -> Result<(), LibError> {
+    for (i, arg) in args.iter().enumerate() {
+        let param = match params.get(i) {
+            Some(p) => p,
+            None => match params.last() {
+                Some(p) if p.variadic => p,
+                _ => break,
+            },
+        };
+        validate_arg(param, arg, i)?;
+    }
+    Ok(())
+}
+
+fn validate_arg(param: &Param, arg: &Arg, index: usize) -> Result<(), LibError> {
+    let text = match (arg, param.kind) {
+        (_, ParamType::StringOrBytes) => return Ok(()),
+        (Arg::Bytes(_, _), ParamType::Bytes) => return Ok(()),
+        (Arg::Chars(_, _), ParamType::Bytes) => {
+            return Err(LibError::ArgTypeError(index, format!("parameter '{}' expects bytes, got a string", param.name)));
+        },
+        (Arg::Bytes(_, _), _) => {
+            return Err(LibError::ArgTypeError(index, format!("parameter '{}' expects {}, got bytes", param.name, param.kind.describe())));
+        },
+        (Arg::Chars(s, _), _) => s,
+    };
+
+    match param.kind {
+        ParamType::Int if text.parse::<i64>().is_err() => {
+            Err(LibError::ArgValueError(index, format!("parameter '{}' expects an integer, got '{}'", param.name, text)))
+        },
+        ParamType::Bool if !matches!(text.as_str(), "true" | "false") => {
+            Err(LibError::ArgValueError(index, format!("parameter '{}' expects 'true' or 'false', got '{}'", param.name, text)))
+        },
+        ParamType::Codepoint if text.chars().count() != 1 => {
+            Err(LibError::ArgValueError(index, format!("parameter '{}' expects exactly one codepoint, got {}", param.name, text.chars().count())))
+        },
+        #[cfg(feature = "regex")]
+        ParamType::Regex => match regex::Regex::new(text) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(LibError::ArgValueError(index, format!("parameter '{}' expects a regular expression: {}", param.name, e))),
+        },
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_usage() {
+        assert_eq!(Param::from_usage("<#1 string to-analyze>"), vec![Param::new("to-analyze", ParamType::String)]);
+        assert_eq!(Param::from_usage("<#1 string base> [<#2 string compare> 1 or more times]")[1],
+            Param { name: "compare", kind: ParamType::String, optional: false, default: None, variadic: true });
+        assert_eq!(Param::from_usage("[<#1 string to-sort> one or more times]")[0],
+            Param { name: "to-sort", kind: ParamType::String, optional: false, default: None, variadic: true });
+        assert_eq!(Param::from_usage("<#1 string separator> [<#2 string to-join> 0 or more times]")[1],
+            Param { name: "to-join", kind: ParamType::String, optional: true, default: None, variadic: true });
+
+        let params = Param::from_usage("<#1 string text> [optional <#2 int width> [optional <#3 codepoint fill>]]");
+        assert_eq!(params.len(), 3);
+        assert!(!params[0].optional);
+        assert_eq!((params[1].kind, params[1].optional), (ParamType::Int, true));
+        assert_eq!((params[2].name, params[2].kind, params[2].optional), ("fill", ParamType::Codepoint, true));
+        assert!(Param::from_usage("").is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown parameter type 'text'")]
+    fn test_from_usage_unknown_type() {
+        Param::from_usage("<#1 text to-analyze>");
+    }
+
+    #[test]
+    fn test_usage_of_all_ops() {
+        // panics for unknown parameter types
+        for new in crate::ops::INDEX.iter() {
+            let usage = new().usage();
+            let params = Param::from_usage(usage);
+            assert_eq!(params.len(), usage.matches("<#").count(), "parameters of usage '{}'", usage);
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let params = Param::from_usage("<#1 string text> <#2 int width> [<#3 codepoint fill> 0 or more times]");
+        let args = |list: &[&str]| Args::from(&list.iter().enumerate().map(|(i, s)| Arg::from_str(s, i)).collect::<Vec<Arg>>());
+
+        assert!(validate(&params, &args(&["a", "-3"])).is_ok());
+        assert!(validate(&params, &args(&["a", "3", "x", "y"])).is_ok());
+        assert!(matches!(validate(&params, &args(&["a", "x"])), Err(LibError::ArgValueError(1, _))));
+        assert!(matches!(validate(&params, &args(&["a", "3", "x", "yz"])), Err(LibError::ArgValueError(3, _))));
+        assert!(matches!(validate(&params, &Args::from(&[Arg::from_bytes(b"a", 0)])), Err(LibError::ArgTypeError(0, _))));
+    }
+}
@@ -33,7 +33,8 @@ impl fmt::Display for Range {
 }
 
 impl Range {
-    pub(crate) fn has(&self, given: usize) -> bool {
+    /// Is `given` within this range?
+    pub fn has(&self, given: usize) -> bool {
         match self {
             Range::IndexIndex(start, end) => *start <= given && given <= *end,
             Range::IndexOpen(start) => *start <= given,
@@ -0,0 +1,191 @@
+//! The registry holds the set of operations known to opstr.
+//! By default, it contains all built-in operations, but library users
+//! can register their own operations which then participate in
+//! `run_op`, `list_matching_ops`, and `run_matching_ops` like built-in ones.
+
+use std::io;
+use std::sync::Arc;
+
+use crate::config::Configuration;
+use crate::errors::LibError;
+use crate::example::Example;
+use crate::input::Args;
+use crate::ops;
+use crate::output::Output;
+use crate::params::Param;
+use crate::range::Range;
+
+/// An operation which can be registered in a `Registry`.
+/// The built-in operations implement this trait as well.
+pub trait Op: Send + Sync {
+    /// `name` gives the name of this function. Must match `[a-zA-Z0-9_-]`
+    fn name(&self) -> &'static str;
+    /// `usage` returns a string describing which arguments shall be provided.
+    /// This string will be shown if the user fails to provide the correct number of arguments.
+    fn usage(&self) -> &'static str;
+    /// `params` specifies the parameters corresponding to the arguments.
+    /// Arguments are validated against them before `priority` and `run` are called.
+    /// The default implementation derives them from `usage`.
+    fn params(&self) -> Vec<Param> { Param::from_usage(self.usage()) }
+    /// `description` gives a single line string describing the functionality.
+    /// Try to limit it to 60 characters.
+    fn description(&self) -> &'static str;
+    /// `acceptable_number_of_arguments` returns which number of arguments can be processed by `run`
+    fn acceptable_number_of_arguments(&self) -> Range;
+    /// `examples` returns worked examples with the output expected for the default `Configuration`
+    fn examples(&self) -> Vec<Example> { vec![] }
+    /// `feature` returns the name of the cargo feature this operation requires (if any)
+    fn feature(&self) -> Option<&'static str> { None }
+    /// `locale_sensitive` tells whether the result depends on the configured locale
+    fn locale_sensitive(&self) -> bool { false }
+    /// `priority` returns a guess between 0.0 and 1.0 how interesting the result
+    /// (given the arguments) is for the user. The implementation can assume
+    /// that `args.len()` is within the range returned by `acceptable_number_of_arguments`.
+    fn priority(&self, args: &Args, conf: &Configuration) -> Result<f32, LibError>;
+    /// `run` implements the operation. The implementation can assume that `args.len()`
+    /// is within the range returned by `acceptable_number_of_arguments`.
+    fn run(&self, args: &Args, conf: &Configuration) -> Result<Output, LibError>;
+    /// `streaming` tells whether `run_stream` is implemented
+    fn streaming(&self) -> bool { false }
+    /// `run_stream` implements the operation for a single argument read chunk by chunk from `input`.
+    /// Thus large files need not be read into memory. Only called if `streaming` returns true.
+    fn run_stream(&self, _input: &mut dyn io::Read, _conf: &Configuration) -> Result<Output, LibError> {
+        Err(LibError::InvalidData(format!("operation '{}' does not support streaming", self.name())))
+    }
+}
+
+/// A set of operations identified by their names
+pub struct Registry {
+    ops: Vec<Arc<dyn Op>>,
+}
+
+impl Registry {
+    /// Create a registry without any operations
+    pub fn empty() -> Self {
+        Self { ops: vec![] }
+    }
+
+    /// Add operation `op` to the registry. If an operation with the same name
+    /// is already registered, it is replaced and returned.
+    pub fn register(&mut self, op: Box<dyn Op>) -> Option<Arc<dyn Op>> {
+        let op: Arc<dyn Op> = Arc::from(op);
+        match self.ops.iter().position(|existing| existing.name() == op.name()) {
+            Some(pos) => Some(std::mem::replace(&mut self.ops[pos], op)),
+            None => {
+                self.ops.push(op);
+                None
+            },
+        }
+    }
+
+    /// Return the operation with name `op_name`, if registered
+    pub fn get(&self, op_name: &str) -> Option<&dyn Op> {
+        self.ops.iter().find(|op| op.name() == op_name).map(|op| op.as_ref())
+    }
+
+    /// Iterate over shared handles of all registered operations in registration order.
+    /// Unlike `iter`, the handles can be moved to other threads.
+    pub(crate) fn shared(&self) -> impl Iterator<Item = &Arc<dyn Op>> {
+        self.ops.iter()
+    }
+
+    /// Iterate over all registered operations in registration order
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Op> {
+        self.ops.iter().map(|op| op.as_ref())
+    }
+
+    /// Number of registered operations
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Is no operation registered?
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
+impl Default for Registry {
+    /// Create a registry with all built-in operations
+    fn default() -> Self {
+        Self {
+            ops: ops::INDEX.iter().map(|new| new()).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Arg;
+
+    struct Shout {}
+
+    impl Op for Shout {
+        fn name(&self) -> &'static str { "shout" }
+        fn usage(&self) -> &'static str { "<#1 string to-shout>" }
+        fn description(&self) -> &'static str { "uppercase string #1 and append an exclamation mark" }
+        fn acceptable_number_of_arguments(&self) -> Range { Range::IndexIndex(1, 1) }
+        fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> { Ok(0.99) }
+
+        fn run(&self, args: &Args, _conf: &Configuration) -> Result<Output, LibError> {
+            let s: &str = args.get(0)?.try_into()?;
+            Ok(format!("{}!", s.to_uppercase()).into())
+        }
+    }
+
+    struct Sleepy {}
+
+    impl Op for Sleepy {
+        fn name(&self) -> &'static str { "sleepy" }
+        fn usage(&self) -> &'static str { "<#1 string ignored>" }
+        fn description(&self) -> &'static str { "take a long time to determine its priority" }
+        fn acceptable_number_of_arguments(&self) -> Range { Range::IndexIndex(1, 1) }
+
+        fn priority(&self, _args: &Args, _conf: &Configuration) -> Result<f32, LibError> {
+            std::thread::sleep(std::time::Duration::from_secs(5));
+            Ok(1.0)
+        }
+
+        fn run(&self, _args: &Args, _conf: &Configuration) -> Result<Output, LibError> { Ok("zzz".into()) }
+    }
+
+    #[test]
+    fn test_custom_op() {
+        let conf = Configuration::default();
+        let args = Args::from(&[Arg::from_str("hi", 0)]);
+
+        let mut registry = Registry::default();
+        let builtin_count = registry.len();
+        assert!(registry.register(Box::new(Shout {})).is_none());
+        assert_eq!(registry.len(), builtin_count + 1);
+
+        let (name, output) = registry.run_op(&conf, &args, "shout").unwrap();
+        assert_eq!(name, "shout");
+        assert_eq!(output, Output::from("HI!"));
+        assert!(registry.list_matching_ops(&conf, &args).iter().any(|(name, _)| *name == "shout"));
+
+        // registering an op with the same name replaces the previous one
+        assert!(registry.register(Box::new(Shout {})).is_some());
+        assert_eq!(registry.len(), builtin_count + 1);
+        assert!(Registry::empty().run_op(&conf, &args, "shout").is_err());
+    }
+
+    #[test]
+    fn test_op_timeout() {
+        let mut conf = Configuration::default();
+        conf.overwrite_timeout(Some(0.2)).unwrap();
+        let args = Args::from(&[Arg::from_str("hi", 0)]);
+
+        let mut registry = Registry::default();
+        registry.register(Box::new(Shout {}));
+        registry.register(Box::new(Sleepy {}));
+
+        let start = std::time::Instant::now();
+        let names = registry.list_matching_ops(&conf, &args).iter().map(|(name, _)| *name).collect::<Vec<&str>>();
+        assert!(start.elapsed() < std::time::Duration::from_secs(3));
+        assert!(names.contains(&"shout"));
+        assert!(!names.contains(&"sleepy"));
+        assert!(conf.overwrite_timeout(Some(0.0)).is_err());
+    }
+}
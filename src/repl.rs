@@ -0,0 +1,456 @@
+//! An interactive mode keeping `Args` and `Configuration` between runs.
+//!
+//! Every line read is either a command (starting with a colon, like `:radix 16`)
+//! or the name of an operation to run with the current arguments.
+//! An empty line runs the current operation again (or all matching operations
+//! if no operation has been selected).
+//!
+//! If stdin is a terminal, lines are read with a line editor completing operation
+//! names and commands when tab is pressed.
+
+use std::io;
+use std::io::{BufRead, IsTerminal, Write};
+
+use rustyline::completion::Completer;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::error::ReadlineError;
+use rustyline::{Context, Editor, Helper};
+
+use crate::config::{Configuration, Source};
+use crate::errors::LibError;
+use crate::example::shell_quote;
+use crate::input::{Arg, Args};
+use crate::registry::Registry;
+
+const HELP: &str = "commands:
+  :op [NAME]          select operation NAME (a unique prefix suffices) or unselect it
+  :run                run the selected operation or all matching operations (same as an empty line)
+  :explain            explain the priorities of all operations for the current arguments
+  :top [N]            only run the N matching operations with the highest priority or unset it
+  :min-priority P     only run matching operations with at least priority P
+  :args [ARG ...]     show or replace all arguments (quote whitespace like in a shell, unquoted 0x... are bytes)
+  :arg N TEXT         replace the one-based N-th argument with TEXT
+  :syntax NAME        represent output in syntax NAME
+  :order NAME         order association entries by insertion, key, or value
+  :radix N            represent integers in radix 2, 10, or 16
+  :hex-upper BOOL     use uppercase letters for hexadecimal values
+  :item [N]           select the zero-based N-th item/row or unselect it
+  :column [NAME]      select column/key NAME or unselect it
+  :locale [NAME]      use locale NAME for locale-dependent operations or unset it
+  :complete PREFIX    list operation names starting with PREFIX (like pressing tab)
+  :dump               show the current arguments and configuration
+  :help               show this help
+  :quit               terminate the session
+NAME                  select operation NAME (a unique prefix suffices) and run it";
+
+/// State of an interactive session
+pub(crate) struct Repl<'r> {
+    registry: &'r Registry,
+    conf: Configuration,
+    args: Args,
+    op: Option<&'static str>,
+}
+
+/// Shall the session continue after a line has been evaluated?
+#[derive(Debug, PartialEq)]
+enum Flow {
+    Continue,
+    Quit,
+}
+
+impl<'r> Repl<'r> {
+    pub(crate) fn new(registry: &'r Registry, conf: Configuration, args: Args) -> Self {
+        Self { registry, conf, args, op: None }
+    }
+
+    /// Return the names of all registered operations starting with `prefix`
+    pub(crate) fn complete(&self, prefix: &str) -> Vec<&'static str> {
+        let mut names = self.registry.iter()
+            .map(|op| op.name())
+            .filter(|name| name.starts_with(prefix))
+            .collect::<Vec<&'static str>>();
+        names.sort();
+        names
+    }
+
+    /// Resolve an operation name given as exact name or unique prefix
+    fn resolve_op(&self, name: &str) -> Result<&'static str, LibError> {
+        if let Some(op) = self.registry.get(name) {
+            return Ok(op.name());
+        }
+
+        match self.complete(name).as_slice() {
+            [] => Err(LibError::UnknownOp(name.to_owned())),
+            [unique] => Ok(unique),
+            candidates => Err(LibError::CLIValueError("op", format!("ambiguous operation name, candidates: {}", candidates.join(", ")))),
+        }
+    }
+
+    /// Run the selected operation or all matching operations and print the result
+    fn run(&self) -> Result<(), LibError> {
+        match self.op {
+            Some(op_name) => {
+                let (_, output) = self.registry.run_op(&self.conf, &self.args, op_name)?;
+                output.print(&self.conf)?;
+            },
+            None => self.registry.run_matching_ops(&self.conf, &self.args)?,
+        }
+        Ok(())
+    }
+
+    /// Evaluate one line of user input
+    fn evaluate(&mut self, line: &str) -> Result<Flow, LibError> {
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.trim().is_empty() {
+            self.run()?;
+            return Ok(Flow::Continue);
+        }
+
+        let (command, param) = match line.trim_start().split_once(char::is_whitespace) {
+            Some((cmd, rest)) => (cmd, rest.trim()),
+            None => (line.trim(), ""),
+        };
+        let optional = |p: &str| if p.is_empty() || p == "none" { None } else { Some(p.to_owned()) };
+
+        match command {
+            ":q" | ":quit" | ":exit" => return Ok(Flow::Quit),
+            ":h" | ":help" => println!("{}", HELP),
+            ":run" => self.run()?,
+            ":explain" => self.registry.explain_ranking(&self.conf, &self.args).print(&self.conf)?,
+            ":top" => match optional(param) {
+                Some(top) => {
+                    let top = top.parse::<usize>().map_err(|_| LibError::CLIValueError("top", "expected a positive integer".to_owned()))?;
+                    self.conf.overwrite_ranking_filters(None, Some(top))?;
+                },
+                None => self.conf.top = None,
+            },
+            ":min-priority" => {
+                let prio = param.parse::<f32>().map_err(|_| LibError::CLIValueError("min-priority", "expected a number between 0.0 and 1.0".to_owned()))?;
+                self.conf.overwrite_ranking_filters(Some(prio), None)?;
+            },
+            ":op" => {
+                self.op = match optional(param) {
+                    Some(name) => Some(self.resolve_op(&name)?),
+                    None => None,
+                };
+            },
+            ":args" => {
+                if !param.is_empty() {
+                    let arguments = split_words(param)?.iter().enumerate()
+                        .map(|(i, (word, verbatim))| match word.starts_with("0x") && *verbatim {
+                            true => Arg::from_hex(word, i),
+                            false => Ok(Arg::from_str(word, i)),
+                        })
+                        .collect::<Result<Vec<Arg>, LibError>>()?;
+                    self.args = Args::from(&arguments);
+                }
+                println!("{}", represent_args(&self.args));
+            },
+            ":arg" => {
+                let (id, text) = param.split_once(' ').unwrap_or((param, ""));
+                let index = match id.parse::<usize>() {
+                    Ok(i) if i > 0 => i - 1,
+                    _ => return Err(LibError::CLIValueError("arg", "expected a one-based argument ID".to_owned())),
+                };
+                if index == self.args.len() {
+                    self.args.add(Arg::from_str(text, index));
+                } else {
+                    self.args.replace(index, Arg::from_str(text, index))?;
+                }
+            },
+            ":syntax" => self.conf.set_syntax(param, Source::Repl("syntax"))?,
+            ":order" => self.conf.set_association_order(param, Source::Repl("order"))?,
+            ":radix" => {
+                let radix = param.parse::<u8>().map_err(|_| LibError::CLIValueError("radix", "expected an integer".to_owned()))?;
+                self.conf.set_radix(radix, Source::Repl("radix"))?;
+            },
+            ":hex-upper" => {
+                let upper = param.parse::<bool>().map_err(|_| LibError::CLIValueError("hex-upper", "expected 'true' or 'false'".to_owned()))?;
+                self.conf.alpha_upper = upper;
+            },
+            ":item" => {
+                self.conf.item = match optional(param) {
+                    Some(item) => Some(item.parse::<isize>().map_err(|_| LibError::CLIValueError("item", "expected an integer".to_owned()))?),
+                    None => None,
+                };
+            },
+            ":column" => self.conf.column = optional(param),
+            ":locale" => match optional(param) {
+                Some(locale) => self.conf.set_locale(locale, Source::Repl("locale"))?,
+                None => self.conf.locale = None,
+            },
+            ":complete" => println!("{}", self.complete(param).join("\n")),
+            ":dump" => {
+                println!("args: {}", represent_args(&self.args));
+                self.conf.dump().print(&self.conf)?;
+                println!("op: {}", self.op.unwrap_or("(all matching operations)"));
+            },
+            cmd if cmd.starts_with(':') => return Err(LibError::CLIValueError("repl", format!("unknown command '{}', try ':help'", cmd))),
+            name => {
+                self.op = Some(self.resolve_op(name)?);
+                self.run()?;
+            },
+        }
+
+        Ok(Flow::Continue)
+    }
+
+    /// Read lines from `input` and evaluate them until `:quit` or end of input
+    pub(crate) fn run_session<R: BufRead>(&mut self, input: R) -> Result<(), LibError> {
+        let mut lines = input.lines();
+        self.run_lines(&mut |prompt| {
+            print!("{}", prompt);
+            io::stdout().flush()?;
+            match lines.next() {
+                Some(line) => Ok(Some(line?)),
+                None => {
+                    println!();
+                    Ok(None)
+                },
+            }
+        })
+    }
+
+    /// Read lines with a line editor completing operation names and evaluate them until `:quit` or end of input
+    pub(crate) fn run_session_with_editor(&mut self) -> Result<(), LibError> {
+        let readline_error = |e: ReadlineError| LibError::IOError(format!("cannot read line: {}", e));
+        let mut editor = Editor::<OpNameCompleter, DefaultHistory>::new().map_err(readline_error)?;
+        editor.set_helper(Some(OpNameCompleter { names: self.complete("") }));
+
+        self.run_lines(&mut |prompt| match editor.readline(prompt) {
+            Ok(line) => {
+                let _ = editor.add_history_entry(line.as_str());
+                Ok(Some(line))
+            },
+            Err(ReadlineError::Eof) | Err(ReadlineError::Interrupted) => Ok(None),
+            Err(e) => Err(readline_error(e)),
+        })
+    }
+
+    /// Evaluate the lines returned by `next_line` (given the prompt) until `:quit` or end of input
+    fn run_lines(&mut self, next_line: &mut dyn FnMut(&str) -> Result<Option<String>, LibError>) -> Result<(), LibError> {
+        loop {
+            let line = match next_line("opstr> ")? {
+                Some(line) => line,
+                None => return Ok(()),
+            };
+
+            match self.evaluate(&line) {
+                Ok(Flow::Continue) => {},
+                Ok(Flow::Quit) => return Ok(()),
+                Err(e) => {
+                    let mut stderr = self.conf.color_scheme.stderr();
+                    self.conf.color_scheme.error_label(&mut stderr, "ERROR")?;
+                    writeln!(stderr, ": {}", e)?;
+                },
+            }
+        }
+    }
+}
+
+/// Completes operation names and commands in the line editor
+struct OpNameCompleter {
+    names: Vec<&'static str>,
+}
+
+impl Completer for OpNameCompleter {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(completions(&self.names, &line[..pos]))
+    }
+}
+
+impl Hinter for OpNameCompleter {
+    type Hint = String;
+}
+
+impl Highlighter for OpNameCompleter {}
+
+impl Validator for OpNameCompleter {}
+
+impl Helper for OpNameCompleter {}
+
+/// Return the start of the word before the cursor and its completions, where `before_cursor` is the line
+/// up to the cursor. The first word is completed as command or operation name, the word after `:op` as operation name.
+fn completions(op_names: &[&'static str], before_cursor: &str) -> (usize, Vec<String>) {
+    let start = before_cursor.rfind(char::is_whitespace).map(|i| i + 1).unwrap_or(0);
+    let (preceding, word) = (before_cursor[..start].trim(), &before_cursor[start..]);
+
+    let candidates: Vec<&str> = match preceding {
+        "" if word.starts_with(':') => HELP.lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .filter(|command| command.starts_with(':'))
+            .collect(),
+        "" | ":op" => op_names.to_vec(),
+        _ => vec![],
+    };
+    (start, candidates.into_iter().filter(|c| c.starts_with(word)).map(|c| c.to_owned()).collect())
+}
+
+/// Represent `args` like on a shell command line, such that `:args` accepts them again.
+/// Bytes are represented in hexadecimal like ``0x68656C6C6F``, hence strings starting with ``0x`` are quoted.
+fn represent_args(args: &Args) -> String {
+    args.iter()
+        .map(|arg| match arg {
+            Arg::Chars(s, _) if s.starts_with("0x") => format!("'{}'", s.replace('\'', "'\\''")),
+            Arg::Chars(s, _) => shell_quote(s),
+            Arg::Bytes(b, _) => format!("0x{}", b.iter().map(|byte| format!("{:02X}", byte)).collect::<String>()),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Split `text` at whitespace into words like a POSIX shell. Every word is returned with whether it is
+/// verbatim (contains no quotes and escapes). Single or double quotes group words, backslash escapes the
+/// next character outside of single quotes.
+fn split_words(text: &str) -> Result<Vec<(String, bool)>, LibError> {
+    let mut words = vec![];
+    let mut current: Option<(String, bool)> = None;
+    let mut quote: Option<char> = None;
+    let mut chars = text.chars();
+
+    while let Some(chr) = chars.next() {
+        match chr {
+            '\\' if quote != Some('\'') => match chars.next() {
+                Some(escaped) => {
+                    let word = current.get_or_insert_with(|| (String::new(), true));
+                    word.0.push(escaped);
+                    word.1 = false;
+                },
+                None => return Err(LibError::CLIValueError("args", "trailing backslash".to_owned())),
+            },
+            '"' | '\'' if quote.is_none() || quote == Some(chr) => {
+                quote = if quote.is_none() { Some(chr) } else { None };
+                current.get_or_insert_with(|| (String::new(), true)).1 = false;
+            },
+            c if c.is_whitespace() && quote.is_none() => {
+                if let Some(word) = current.take() {
+                    words.push(word);
+                }
+            },
+            c => current.get_or_insert_with(|| (String::new(), true)).0.push(c),
+        }
+    }
+
+    if quote.is_some() {
+        return Err(LibError::CLIValueError("args", "unterminated quote".to_owned()));
+    }
+    if let Some(word) = current.take() {
+        words.push(word);
+    }
+
+    Ok(words)
+}
+
+/// Start an interactive session with operations of `registry`, reading commands from `input`
+pub fn repl<R: BufRead>(registry: &Registry, conf: Configuration, args: Args, input: R) -> Result<(), LibError> {
+    Repl::new(registry, conf, args).run_session(input)
+}
+
+/// Start an interactive session with operations of `registry`, reading commands from stdin.
+/// If stdin is a terminal, a line editor with completion of operation names is used.
+pub fn repl_stdin(registry: &Registry, conf: Configuration, args: Args) -> Result<(), LibError> {
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        Repl::new(registry, conf, args).run_session_with_editor()
+    } else {
+        repl(registry, conf, args, stdin.lock())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words() {
+        let words = |text: &str| split_words(text).unwrap().into_iter().map(|(word, _)| word).collect::<Vec<String>>();
+        assert_eq!(words("a  b"), vec!["a", "b"]);
+        assert_eq!(words("\"a b\" c\\\""), vec!["a b", "c\""]);
+        assert_eq!(words("\"\""), vec![""]);
+        assert!(split_words("\"a").is_err());
+        assert_eq!(words("'it'\\''s \"x\" \\'"), vec!["it's \"x\" \\"]);
+        assert_eq!(split_words("0x61 '0x61' 0\\x61").unwrap(), vec![
+            ("0x61".to_owned(), true), ("0x61".to_owned(), false), ("0x61".to_owned(), false),
+        ]);
+    }
+
+    #[test]
+    fn test_completions() {
+        let names = ["count-codepoints", "count-utf8-bytes", "sort"];
+        assert_eq!(completions(&names, "count-c"), (0, vec!["count-codepoints".to_owned()]));
+        assert_eq!(completions(&names, ":op so"), (4, vec!["sort".to_owned()]));
+        assert_eq!(completions(&names, ":ra"), (0, vec![":radix".to_owned()]));
+        assert_eq!(completions(&names, ":args cou"), (6, vec![]));
+        assert_eq!(completions(&names, "").1.len(), 3);
+    }
+
+    #[test]
+    fn test_represent_args() {
+        let args = Args::from(&[Arg::from_str("hello", 0), Arg::from_str("it's a test", 1), Arg::from_str("", 2)]);
+        assert_eq!(represent_args(&args), "hello 'it'\\''s a test' ''");
+        assert_eq!(represent_args(&Args::from(&[Arg::from_bytes(b"hi", 0)])), "0x6869");
+        assert_eq!(represent_args(&Args::from(&[Arg::from_str("0x68", 0)])), "'0x68'");
+    }
+
+    #[test]
+    fn test_settings_keep_other_sources() {
+        let registry = Registry::default();
+        let mut conf = Configuration::default();
+        conf.overwrite_with_clap(None, Some(1), None, None, None, None, None, None).unwrap();
+        let mut repl = Repl::new(&registry, conf, Args::from(&[]));
+        repl.evaluate(":syntax json").unwrap();
+        repl.evaluate(":order key").unwrap();
+        assert_eq!(repl.conf.sources.get("syntax"), Some(&Source::Repl("syntax")));
+        assert_eq!(repl.conf.sources.get("association_order"), Some(&Source::Repl("order")));
+        assert_eq!(repl.conf.sources.get("item"), Some(&Source::CommandLine("item")));
+        assert_eq!(repl.conf.item, Some(1));
+    }
+
+    #[test]
+    fn test_args_round_trip() {
+        let registry = Registry::default();
+        let args = Args::from(&[
+            Arg::from_str("hello", 0), Arg::from_str("it's a test", 1), Arg::from_str("", 2),
+            Arg::from_str("0x68", 3), Arg::from_bytes(b"hi", 4),
+        ]);
+        let mut repl = Repl::new(&registry, Configuration::default(), Args::from(&[]));
+        repl.evaluate(&format!(":args {}", represent_args(&args))).unwrap();
+        assert_eq!(repl.args, args);
+        assert!(repl.evaluate(":args 0xZZ").is_err());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let registry = Registry::default();
+        let mut repl = Repl::new(&registry, Configuration::default(), Args::from(&[]));
+
+        assert!(repl.complete("count-").contains(&"count-codepoints"));
+        assert_eq!(repl.evaluate(":op count-codep").unwrap(), Flow::Continue);
+        assert_eq!(repl.op, Some("count-codepoints"));
+        assert!(repl.evaluate(":op count-").is_err());
+        assert!(repl.evaluate(":op").is_ok());
+        assert_eq!(repl.op, None);
+
+        repl.evaluate(":args \"hello world\" x").unwrap();
+        assert_eq!(repl.args, Args::from(&[Arg::from_str("hello world", 0), Arg::from_str("x", 1)]));
+        repl.evaluate(":arg 2 y z").unwrap();
+        assert_eq!(repl.args.get(1).unwrap(), &Arg::from_str("y z", 1));
+
+        repl.evaluate(":radix 16").unwrap();
+        assert_eq!(repl.conf.radix, 16);
+        assert_eq!(repl.conf.sources.get("radix"), Some(&Source::Repl("radix")));
+        assert!(repl.evaluate(":radix 7").is_err());
+        repl.evaluate(":item -1").unwrap();
+        assert_eq!(repl.conf.item, Some(-1));
+        repl.evaluate(":item none").unwrap();
+        assert_eq!(repl.conf.item, None);
+
+        assert!(repl.evaluate(":unknown").is_err());
+        assert_eq!(repl.evaluate(":quit").unwrap(), Flow::Quit);
+    }
+}
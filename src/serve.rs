@@ -0,0 +1,138 @@
+//! A batch mode speaking JSON lines, such that tools can run many operations with one process.
+//!
+//! Every line read is a request like
+//! ``{"id": 1, "op": "count-codepoints", "args": ["hello"], "config": {"radix": 16}}``.
+//! `id` (any JSON value) and `config` are optional. For every request, one line with a response like
+//! ``{"id":1,"op":"count-codepoints","result":{"type":"scalar",…}}`` or ``{"id":1,"code":"…","class":"…",…}`` is written,
+//! where the members following `id` are the ones of `LibError::represent_json` (code ``cli-value`` with flag ``serve``
+//! if the line is not a valid request).
+//! The `Configuration` is shared by all requests and `config` only overwrites settings for one request.
+
+use std::io::{BufRead, Write};
+
+use crate::config::Configuration;
+use crate::errors::LibError;
+use crate::input::{Arg, Args};
+use crate::output::Output;
+use crate::registry::Registry;
+
+use serde_json::json;
+
+/// One request read from a line of input
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Request {
+    #[serde(default)]
+    id: serde_json::Value,
+    op: String,
+    #[serde(default)]
+    args: Vec<String>,
+    #[serde(default)]
+    config: RequestConfig,
+}
+
+/// Settings overwriting the shared `Configuration` for one request
+#[derive(Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RequestConfig {
+    radix: Option<u8>,
+    hex_upper: Option<bool>,
+    item: Option<isize>,
+    column: Option<String>,
+    locale: Option<String>,
+    association_order: Option<String>,
+}
+
+impl RequestConfig {
+    /// Return a copy of `conf` with these settings applied. Settings not given keep their value of `conf`.
+    pub(crate) fn apply(&self, conf: &Configuration) -> Result<Configuration, LibError> {
+        let mut result = conf.clone();
+        // NOTE: item and column are always assigned, so we pass on the shared values if not given
+        result.overwrite_with_clap(
+            self.radix, self.item.or(conf.item), self.column.clone().or_else(|| conf.column.clone()), self.hex_upper,
+            None, self.locale.clone(), None, self.association_order.clone(),
+        )?;
+        Ok(result)
+    }
+}
+
+/// Evaluate the request given as JSON in `line` and return the response as JSON on a single line
+fn respond(registry: &Registry, conf: &Configuration, line: &str) -> String {
+    let request: Request = match serde_json::from_str(line) {
+        Ok(r) => r,
+        Err(e) => return error_response(serde_json::Value::Null, None, &LibError::CLIValueError("serve", format!("invalid request: {}", e))),
+    };
+
+    match run_request(registry, conf, &request) {
+        Ok((op_name, conf, output)) => json!({"id": request.id, "op": op_name, "result": output.reduce(&conf).to_json(&conf)}).to_string(),
+        Err(e) => error_response(request.id.clone(), Some(&request.op), &e),
+    }
+}
+
+/// Represent `err` of the request with `id` for operation `op_name` as JSON on a single line
+fn error_response(id: serde_json::Value, op_name: Option<&str>, err: &LibError) -> String {
+    let mut response = serde_json::Map::new();
+    response.insert("id".to_owned(), id);
+    response.extend(err.to_json(op_name));
+    serde_json::Value::Object(response).to_string()
+}
+
+/// Run the operation of `request` and return its name with the configuration used and its output
+fn run_request(registry: &Registry, conf: &Configuration, request: &Request) -> Result<(&'static str, Configuration, Output), LibError> {
+    let conf = request.config.apply(conf)?;
+    let args = Args::from(&request.args.iter().enumerate().map(|(i, arg)| Arg::from_str(arg, i)).collect::<Vec<Arg>>());
+    let (op_name, output) = registry.run_op(&conf, &args, &request.op)?;
+    Ok((op_name, conf, output))
+}
+
+/// Read JSON requests line by line from `input` and write one JSON response per line to `output`
+/// until the end of input. Empty lines are skipped.
+pub fn serve<R: BufRead, W: Write>(registry: &Registry, conf: &Configuration, input: R, mut output: W) -> Result<(), LibError> {
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        writeln!(output, "{}", respond(registry, conf, &line))?;
+        output.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_serve() {
+        let requests = r#"{"id": 1, "op": "count-codepoints", "args": ["äöü"]}
+
+{"op": "count-codepoints", "args": ["hello"], "config": {"radix": 16}}
+{"id": "x", "op": "no-such-op"}
+not json
+"#;
+        let mut responses = vec![];
+        serve(&Registry::default(), &Configuration::default(), Cursor::new(requests), &mut responses).unwrap();
+
+        let lines = String::from_utf8(responses).unwrap().lines().map(|l| l.to_owned()).collect::<Vec<String>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with(r#"{"id":1,"op":"count-codepoints","result":{"type":"scalar","data":3"#));
+        assert!(lines[1].starts_with(r#"{"id":null,"op":"count-codepoints","result":"#));
+        assert_eq!(lines[2], r#"{"id":"x","code":"unknown-op","class":"usage","message":"unknown operation 'no-such-op'","arg_index":null,"flag":null,"reason":null,"op":"no-such-op"}"#);
+        assert!(lines[3].starts_with(r#"{"id":null,"code":"cli-value","class":"usage","message":"#));
+        assert!(lines[3].ends_with(r#""flag":"serve","reason":null,"op":null}"#));
+    }
+
+    #[test]
+    fn test_serve_keeps_shared_configuration() {
+        let conf = Configuration { item: Some(1), ..Configuration::default() };
+        let requests = "{\"op\": \"split\", \"args\": [\"a,b,c\", \",\"]}\n";
+        let mut responses = vec![];
+        serve(&Registry::default(), &conf, Cursor::new(requests), &mut responses).unwrap();
+
+        let response = String::from_utf8(responses).unwrap();
+        assert!(response.starts_with(r#"{"id":null,"op":"split","result":{"type":"scalar","data":"b""#), "{}", response);
+    }
+}
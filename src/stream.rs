@@ -0,0 +1,81 @@
+//! Chunked reading of a single argument for operations supporting streaming.
+//!
+//! Instead of reading a large file or stdin into memory, operations like
+//! `count-utf8-bytes` consume it chunk by chunk with the functions of this module.
+
+use std::io;
+
+use crate::errors::LibError;
+
+/// Number of bytes read at once
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Call `f` with every chunk of bytes read from `input` until its end or until `f` fails
+pub(crate) fn for_each_chunk(input: &mut dyn io::Read, mut f: impl FnMut(&[u8]) -> Result<(), LibError>) -> Result<(), LibError> {
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    loop {
+        match input.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(n) => f(&buffer[..n])?,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Call `f` with every chunk of UTF-8 text read from `input` until its end.
+/// A codepoint split between two reads is passed on with the later chunk.
+/// Fails with an `ArgValueError` for the first argument if the content is not valid UTF-8.
+pub(crate) fn for_each_str_chunk(input: &mut dyn io::Read, mut f: impl FnMut(&str)) -> Result<(), LibError> {
+    let invalid = |offset: usize| LibError::ArgValueError(0, format!("expected UTF-8 content, got non-UTF-8 bytes at byte offset {}", offset));
+    let mut pending: Vec<u8> = vec![];
+    let mut offset = 0;
+
+    for_each_chunk(input, |chunk| {
+        pending.extend_from_slice(chunk);
+        let text = match std::str::from_utf8(&pending) {
+            Ok(s) => s,
+            // an incomplete codepoint at the end is completed by the next chunk
+            Err(e) if e.error_len().is_none() => std::str::from_utf8(&pending[..e.valid_up_to()]).map_err(|_| invalid(offset))?,
+            Err(e) => return Err(invalid(offset + e.valid_up_to())),
+        };
+        f(text);
+
+        let consumed = text.len();
+        offset += consumed;
+        pending.drain(..consumed);
+        Ok(())
+    })?;
+
+    match pending.is_empty() {
+        true => Ok(()),
+        false => Err(invalid(offset)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_each_str_chunk() {
+        // a reader returning one byte per read splits every multi-byte codepoint
+        struct Trickle<'a>(&'a [u8]);
+        impl io::Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let n = self.0.len().min(buf.len()).min(1);
+                buf[..n].copy_from_slice(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(n)
+            }
+        }
+
+        let mut collected = String::new();
+        for_each_str_chunk(&mut Trickle("häöü€😀".as_bytes()), |s| collected.push_str(s)).unwrap();
+        assert_eq!(collected, "häöü€😀");
+
+        assert!(matches!(for_each_str_chunk(&mut Trickle(b"ab\xFFcd"), |_| {}), Err(LibError::ArgValueError(0, _))));
+        assert!(for_each_str_chunk(&mut Trickle(b"ab\xE2\x82"), |_| {}).is_err());
+        assert!(for_each_str_chunk(&mut io::Cursor::new(b"".to_vec()), |_| {}).is_ok());
+    }
+}
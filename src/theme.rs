@@ -0,0 +1,224 @@
+//! User-defined color themes as alternative to the built-in color schemes.
+//!
+//! A theme assigns a style to every semantic role of the output. A style is a comma-separated list
+//! of a color and attributes like ``yellow,bold``. Colors are the names ``black``, ``blue``, ``green``,
+//! ``red``, ``cyan``, ``magenta``, ``yellow``, and ``white``, an ANSI 256 color index like ``208``,
+//! or an RGB color like ``#ff8800``. Attributes are ``bold``, ``italic``, and ``underline``.
+//!
+//! * A theme file ``$XDG_CONFIG_HOME/opstr/themes/{name}.toml`` contains one entry ``role = "style"`` per role.
+//! * Environment variable `OPSTR_THEME` specifies theme ``custom`` like ``section=yellow,bold;error=#ff0000``.
+//!
+//! The roles are ``section`` (the operation name of a section), ``note``, ``error``, ``keyword``,
+//! ``outer-wrapper``, ``outer-separator``, ``inner-wrapper``, and ``inner-separator``.
+//! Roles without a style are written without colors.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use termcolor::{Color, ColorSpec};
+
+use crate::config::Configuration;
+use crate::errors::LibError;
+
+/// Name of the theme specified by environment variable `OPSTR_THEME`
+pub(crate) const ENV_THEME_NAME: &str = "custom";
+
+/// The semantic roles a theme assigns styles to
+const ROLES: [&str; 8] = ["section", "note", "error", "keyword", "outer-wrapper", "outer-separator", "inner-wrapper", "inner-separator"];
+
+/// A named set of styles, one per semantic role of the output
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    name: String,
+    /// the style specifications per role as provided by the user (used to hash and dump the theme)
+    spec: BTreeMap<String, String>,
+    pub(crate) section: ColorSpec,
+    pub(crate) note: ColorSpec,
+    pub(crate) error: ColorSpec,
+    pub(crate) keyword: ColorSpec,
+    pub(crate) outer_wrapper: ColorSpec,
+    pub(crate) outer_separator: ColorSpec,
+    pub(crate) inner_wrapper: ColorSpec,
+    pub(crate) inner_separator: ColorSpec,
+}
+
+impl Hash for Theme {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.spec.hash(state);
+    }
+}
+
+/// Parse a style like ``yellow,bold`` into a `ColorSpec`
+fn parse_style(style: &str) -> Result<ColorSpec, String> {
+    let mut spec = ColorSpec::new();
+    for word in style.split(',').map(|w| w.trim().to_ascii_lowercase()).filter(|w| !w.is_empty()) {
+        match word.as_str() {
+            "bold" => { spec.set_bold(true); },
+            "italic" => { spec.set_italic(true); },
+            "underline" => { spec.set_underline(true); },
+            _ => {
+                let color = match word.strip_prefix('#') {
+                    Some(hex) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                        let component = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+                        Color::Rgb(component(0), component(2), component(4))
+                    },
+                    Some(_) => return Err(format!("invalid RGB color '{}', expected e.g. '#ff8800'", word)),
+                    None => match word.parse::<u8>() {
+                        Ok(index) => Color::Ansi256(index),
+                        Err(_) => word.parse::<Color>().map_err(|_| format!("unknown color or attribute '{}'", word))?,
+                    },
+                };
+                spec.set_fg(Some(color));
+            },
+        }
+    }
+    Ok(spec)
+}
+
+impl Theme {
+    /// Create a theme called `name` from `(role, style)` pairs
+    fn from_roles<'s>(name: &str, roles: impl Iterator<Item=(&'s str, &'s str)>) -> Result<Theme, String> {
+        let mut theme = Theme {
+            name: name.to_owned(),
+            spec: BTreeMap::new(),
+            section: ColorSpec::new(),
+            note: ColorSpec::new(),
+            error: ColorSpec::new(),
+            keyword: ColorSpec::new(),
+            outer_wrapper: ColorSpec::new(),
+            outer_separator: ColorSpec::new(),
+            inner_wrapper: ColorSpec::new(),
+            inner_separator: ColorSpec::new(),
+        };
+
+        for (role, style) in roles {
+            let role = role.trim();
+            let spec = parse_style(style).map_err(|e| format!("role '{}': {}", role, e))?;
+            *match role {
+                "section" => &mut theme.section,
+                "note" => &mut theme.note,
+                "error" => &mut theme.error,
+                "keyword" => &mut theme.keyword,
+                "outer-wrapper" => &mut theme.outer_wrapper,
+                "outer-separator" => &mut theme.outer_separator,
+                "inner-wrapper" => &mut theme.inner_wrapper,
+                "inner-separator" => &mut theme.inner_separator,
+                _ => return Err(format!("unknown role '{}', expected one of {}", role, ROLES.join(", "))),
+            } = spec;
+            theme.spec.insert(role.to_owned(), style.trim().to_owned());
+        }
+
+        Ok(theme)
+    }
+
+    /// Parse a theme specification like ``section=yellow,bold;error=#ff0000`` into a theme called `name`
+    pub fn parse(name: &str, spec: &str) -> Result<Theme, String> {
+        let mut roles = vec![];
+        for assignment in spec.split(';').filter(|a| !a.trim().is_empty()) {
+            match assignment.split_once('=') {
+                Some(role_style) => roles.push(role_style),
+                None => return Err(format!("expected 'role=style', got '{}'", assignment.trim())),
+            }
+        }
+        Self::from_roles(name, roles.into_iter())
+    }
+
+    /// Load the theme file at `filepath`. The name of the theme is the file name without extension.
+    pub fn load(filepath: &Path) -> Result<Theme, LibError> {
+        let error = |msg: String| LibError::ConfigFileError(filepath.display().to_string(), msg);
+        let content = fs::read_to_string(filepath).map_err(|e| error(e.to_string()))?;
+        let roles: BTreeMap<String, String> = toml::from_str(&content).map_err(|e| error(e.message().to_owned()))?;
+        let name = filepath.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+        Self::from_roles(&name, roles.iter().map(|(r, s)| (r.as_str(), s.as_str()))).map_err(error)
+    }
+
+    /// The directory containing theme files, i.e. ``$XDG_CONFIG_HOME/opstr/themes``
+    pub fn directory() -> Option<PathBuf> {
+        Configuration::config_home().map(|dir| dir.join("opstr").join("themes"))
+    }
+
+    /// Return the names of all themes available, i.e. those of the theme directory
+    /// (in alphabetical order) and ``custom`` if `OPSTR_THEME` is set
+    pub fn available() -> Vec<String> {
+        let mut names = vec![];
+        if let Some(entries) = Self::directory().and_then(|dir| fs::read_dir(dir).ok()) {
+            for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+                if path.extension().is_some_and(|ext| ext == "toml") {
+                    if let Some(stem) = path.file_stem() {
+                        names.push(stem.to_string_lossy().into_owned());
+                    }
+                }
+            }
+        }
+        names.sort();
+
+        if env::var_os("OPSTR_THEME").is_some() && !names.iter().any(|n| n == ENV_THEME_NAME) {
+            names.push(ENV_THEME_NAME.to_owned());
+        }
+        names
+    }
+
+    /// Return the theme called `name` (or None, if there is no such theme).
+    /// Theme ``custom`` is taken from `OPSTR_THEME` if set.
+    pub fn by_name(name: &str) -> Result<Option<Theme>, LibError> {
+        if name == ENV_THEME_NAME {
+            if let Some(theme) = Self::from_env()? {
+                return Ok(Some(theme));
+            }
+        }
+
+        // NOTE: names must not address files outside the theme directory
+        if name.is_empty() || name.contains(['/', '\\', '.']) {
+            return Ok(None);
+        }
+        match Self::directory().map(|dir| dir.join(format!("{}.toml", name))) {
+            Some(filepath) if filepath.is_file() => Ok(Some(Self::load(&filepath)?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Return the theme specified by environment variable `OPSTR_THEME` if it is set
+    pub fn from_env() -> Result<Option<Theme>, LibError> {
+        match env::var("OPSTR_THEME") {
+            Ok(spec) => Self::parse(ENV_THEME_NAME, &spec)
+                .map(Some)
+                .map_err(|e| LibError::CLIValueError("OPSTR_THEME", e)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// The name of this theme
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Represent the styles of this theme in the syntax accepted by `Theme::parse`
+    pub fn represent(&self) -> String {
+        self.spec.iter().map(|(role, style)| format!("{}={}", role, style)).collect::<Vec<String>>().join(";")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let theme = Theme::parse("mine", "section=yellow,bold; error=#FF0000 ;note=208,underline;").unwrap();
+        assert_eq!(theme.name(), "mine");
+        assert_eq!(theme.section, ColorSpec::new().set_fg(Some(Color::Yellow)).set_bold(true).clone());
+        assert_eq!(theme.error, ColorSpec::new().set_fg(Some(Color::Rgb(255, 0, 0))).clone());
+        assert_eq!(theme.note, ColorSpec::new().set_fg(Some(Color::Ansi256(208))).set_underline(true).clone());
+        assert_eq!(theme.keyword, ColorSpec::new());
+        assert_eq!(theme.represent(), "error=#FF0000;note=208,underline;section=yellow,bold");
+
+        assert!(Theme::parse("x", "sections=red").unwrap_err().contains("unknown role 'sections'"));
+        assert!(Theme::parse("x", "note=purple").unwrap_err().contains("unknown color or attribute 'purple'"));
+        assert!(Theme::parse("x", "note=#12345").is_err());
+        assert!(Theme::parse("x", "red").is_err());
+    }
+}
@@ -0,0 +1,206 @@
+//! Golden-file regression tests running the opstr executable.
+//!
+//! Every operation has a fixture ``tests/golden/{op name}.txt`` with cases. A case is a line
+//! ``=== ["--radix", "16", "hello"]`` listing the CLI arguments (flags first) as JSON array.
+//! It is followed by one section ``--- {syntax}`` per `Syntax` with the expected stdout
+//! of ``opstr --color-scheme none --syntax {syntax} --op {op name} {arguments…}``.
+//! If the process fails, the section ends with ``[exit status N]`` and the stderr content.
+//! Lines starting with ``#`` before the first case are comments. ``{fixtures}`` in an argument is replaced
+//! by the path of ``tests/golden``, e.g. to refer to a configuration file in ``tests/golden/config``.
+//!
+//! Run ``OPSTR_BLESS=1 cargo test --test golden`` to (re)write all expected outputs with the current
+//! behavior. Missing fixtures are created from the examples of the operation.
+//!
+//! ``tests/golden/op-names.txt`` lists all operation names ever released. As promised by the README,
+//! none of them must disappear. Blessing appends names of new operations.
+
+#![cfg(all(feature = "digest", feature = "regex", feature = "icu"))]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+
+use opstr::{Registry, Syntax};
+
+/// One invocation of an operation with its expected output per syntax
+struct Case {
+    args: Vec<String>,
+    expected: Vec<(String, String)>,
+}
+
+/// Content of a fixture file
+struct Fixture {
+    comments: Vec<String>,
+    cases: Vec<Case>,
+}
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden")
+}
+
+fn blessing() -> bool {
+    env::var_os("OPSTR_BLESS").is_some()
+}
+
+impl Fixture {
+    fn parse(content: &str) -> Result<Self, String> {
+        let mut fixture = Fixture { comments: vec![], cases: vec![] };
+
+        for line in content.split_inclusive('\n') {
+            if let Some(args) = line.strip_prefix("=== ") {
+                let args = serde_json::from_str(args).map_err(|e| format!("invalid case {}: {}", args.trim_end(), e))?;
+                fixture.cases.push(Case { args, expected: vec![] });
+            } else if let Some(syntax) = line.strip_prefix("--- ") {
+                match fixture.cases.last_mut() {
+                    Some(case) => case.expected.push((syntax.trim_end().to_owned(), String::new())),
+                    None => return Err(format!("section '{}' before the first case", syntax.trim_end())),
+                }
+            } else if let Some((_, output)) = fixture.cases.last_mut().and_then(|case| case.expected.last_mut()) {
+                output.push_str(line);
+            } else if line.starts_with('#') || line.trim().is_empty() {
+                fixture.comments.push(line.trim_end().to_owned());
+            } else {
+                return Err(format!("unexpected line '{}'", line.trim_end()));
+            }
+        }
+
+        Ok(fixture)
+    }
+
+    fn represent(&self) -> String {
+        let mut content = String::new();
+        for comment in self.comments.iter() {
+            content.push_str(comment);
+            content.push('\n');
+        }
+        for case in self.cases.iter() {
+            content.push_str(&format!("=== {}\n", serde_json::to_string(&case.args).unwrap()));
+            for (syntax, output) in case.expected.iter() {
+                content.push_str(&format!("--- {}\n{}", syntax, output));
+            }
+        }
+        content
+    }
+}
+
+/// Run operation `op_name` with CLI arguments `args` in `syntax` and return what the fixture expects
+fn run(workdir: &Path, op_name: &str, syntax: &str, args: &[String]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_opstr"))
+        .args(["--color-scheme", "none", "--syntax", syntax, "--op", op_name])
+        .args(args.iter().map(|arg| arg.replace("{fixtures}", &golden_dir().display().to_string())))
+        // isolate from configuration files and environment variables of the user
+        .env_clear()
+        .env("XDG_CONFIG_HOME", workdir)
+        .env("HOME", workdir)
+        .current_dir(workdir)
+        .output()
+        .expect("opstr executable can be run");
+
+    let mut result = String::from_utf8_lossy(&output.stdout).into_owned();
+    if !output.status.success() {
+        if !result.is_empty() && !result.ends_with('\n') {
+            result.push('\n');
+        }
+        result.push_str(&format!("[exit status {}]\n", output.status.code().unwrap_or(-1)));
+        result.push_str(&String::from_utf8_lossy(&output.stderr));
+    }
+    if !result.is_empty() && !result.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Check the fixture of operation `op_name` and return a description of every mismatch
+fn check_op(workdir: &Path, op_name: &str, examples: Vec<Vec<String>>) -> Vec<String> {
+    let filepath = golden_dir().join(format!("{}.txt", op_name));
+    let mut fixture = match fs::read_to_string(&filepath) {
+        Ok(content) => match Fixture::parse(&content) {
+            Ok(f) => f,
+            Err(e) => return vec![format!("{}: {}", filepath.display(), e)],
+        },
+        Err(_) if blessing() => Fixture {
+            comments: vec![format!("# Golden outputs of operation '{}'. Regenerate them with OPSTR_BLESS=1 cargo test --test golden", op_name)],
+            cases: examples.into_iter().map(|args| Case { args, expected: vec![] }).collect(),
+        },
+        Err(e) => return vec![format!("{}: cannot be read ({}), run with OPSTR_BLESS=1 to create it", filepath.display(), e)],
+    };
+
+    if fixture.cases.is_empty() {
+        if blessing() {
+            fs::write(&filepath, fixture.represent()).expect("fixture can be written");
+        }
+        return vec![format!("{}: no cases specified, add a line like === [\"hello\"]", filepath.display())];
+    }
+
+    let mut mismatches = vec![];
+    for case in fixture.cases.iter_mut() {
+        let mut actual = vec![];
+        for syntax in Syntax::all_as_slice() {
+            let output = run(workdir, op_name, syntax.name(), &case.args);
+            match case.expected.iter().find(|(name, _)| name == syntax.name()) {
+                Some((_, expected)) if *expected == output => {},
+                Some((_, expected)) => mismatches.push(format!(
+                    "{} {:?} --syntax {}:\n  expected: {:?}\n  actual:   {:?}", op_name, case.args, syntax.name(), expected, output
+                )),
+                None => mismatches.push(format!("{} {:?}: no expected output for syntax {}", op_name, case.args, syntax.name())),
+            }
+            actual.push((syntax.name().to_owned(), output));
+        }
+        case.expected = actual;
+    }
+
+    if blessing() {
+        fs::write(&filepath, fixture.represent()).expect("fixture can be written");
+        return vec![];
+    }
+    mismatches
+}
+
+#[test]
+fn golden_outputs() {
+    let workdir = env::temp_dir().join(format!("opstr-golden-{}", std::process::id()));
+    fs::create_dir_all(&workdir).unwrap();
+
+    let registry = Registry::default();
+    let ops = registry.iter()
+        .map(|op| (op.name(), op.examples().iter().map(|ex| ex.args.iter().map(|a| a.to_string()).collect()).collect()))
+        .collect::<Vec<(&'static str, Vec<Vec<String>>)>>();
+
+    // operations are checked concurrently, since every check spawns one process per case and syntax
+    let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+    let chunk_size = ops.len().div_ceil(workers).max(1);
+    let mismatches = thread::scope(|scope| {
+        let handles = ops.chunks(chunk_size).map(|chunk| {
+            let workdir = &workdir;
+            scope.spawn(move || chunk.iter().flat_map(|(name, examples)| check_op(workdir, name, examples.clone())).collect::<Vec<String>>())
+        }).collect::<Vec<_>>();
+        handles.into_iter().flat_map(|h| h.join().unwrap()).collect::<Vec<String>>()
+    });
+
+    let _ = fs::remove_dir_all(&workdir);
+    assert!(mismatches.is_empty(), "{} mismatches (run with OPSTR_BLESS=1 to accept them):\n{}", mismatches.len(), mismatches.join("\n"));
+}
+
+#[test]
+fn op_names_never_disappear() {
+    let filepath = golden_dir().join("op-names.txt");
+    let content = fs::read_to_string(&filepath).unwrap_or_default();
+    let released = content.lines().filter(|l| !l.starts_with('#') && !l.trim().is_empty()).collect::<Vec<&str>>();
+    let registry = Registry::default();
+
+    let disappeared = released.iter().filter(|name| registry.get(name).is_none()).collect::<Vec<_>>();
+    assert!(disappeared.is_empty(), "operations must never disappear, but these are missing: {:?}", disappeared);
+
+    let unlisted = registry.iter().map(|op| op.name()).filter(|name| !released.contains(name)).collect::<Vec<&str>>();
+    if blessing() {
+        let mut content = content.clone();
+        for name in unlisted.iter() {
+            content.push_str(&format!("{}\n", name));
+        }
+        fs::write(&filepath, content).unwrap();
+    } else {
+        assert!(unlisted.is_empty(), "new operations must be listed in {} (run with OPSTR_BLESS=1): {:?}", filepath.display(), unlisted);
+    }
+}